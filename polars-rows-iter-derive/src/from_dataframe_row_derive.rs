@@ -1,33 +1,74 @@
 use itertools::Itertools;
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::{
     punctuated::Punctuated, spanned::Spanned, DeriveInput, Expr, ExprLit, Field, GenericArgument, GenericParam,
-    Generics, Ident, Lifetime, LifetimeParam, LitStr, PathArguments, Token, Type, TypeReference,
+    Generics, Ident, Lifetime, LifetimeParam, LitStr, PathArguments, Token, Type, TypeReference, Visibility,
 };
 
 const ROW_ITERATOR_NAME: &str = "RowsIterator";
+const ROW_GETTER_NAME: &str = "RowGetter";
 
 #[derive(Debug)]
 struct FieldInfo {
     pub name: String,
     pub ident: Ident,
-    pub dtype_ident: Ident,
     pub iter_ident: Ident,
+    pub column_ident: Ident,
+    pub null_policy_ident: Ident,
     pub inner_ty: Type,
     pub is_optional: bool,
     pub column_name_expr: Expr,
+    pub on_null: Option<OnNull>,
+}
+
+/// Null policy of a field given by `#[column(on_null = "...")]`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum OnNull {
+    Error,
+    Skip,
+    Default,
+}
+
+impl ToTokens for OnNull {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            OnNull::Error => quote! { ::polars_rows_iter::NullPolicy::Error },
+            OnNull::Skip => quote! { ::polars_rows_iter::NullPolicy::SkipRow },
+            OnNull::Default => quote! { ::polars_rows_iter::NullPolicy::UseDefault },
+        });
+    }
+}
+
+/// Field with the `#[row_index]` attribute, which is filled with the row index instead of a column value
+struct RowIndexField {
+    ident: Ident,
+    ty: Type,
 }
 
 struct Context {
     struct_ident: Ident,
+    struct_vis: Visibility,
     builder_struct_ident: Ident,
     iter_struct_ident: Ident,
+    getter_struct_ident: Ident,
     fields_list: Vec<FieldInfo>,
+    row_index_fields: Vec<RowIndexField>,
     has_lifetime: bool,
+    cast: bool,
+    filter: Option<String>,
+}
+
+#[derive(Debug, Default, deluxe::ExtractAttributes)]
+#[deluxe(attributes(rows), default)]
+struct RowsStructAttributes {
+    cast: deluxe::Flag,
+    filter: Option<String>,
 }
 
-pub fn from_dataframe_row_derive_impl(ast: DeriveInput) -> TokenStream {
+pub fn from_dataframe_row_derive_impl(mut ast: DeriveInput) -> TokenStream {
+    let struct_attrs: RowsStructAttributes = deluxe::extract_attributes(&mut ast).unwrap();
+
     let struct_data = match &ast.data {
         syn::Data::Struct(data_struct) => data_struct,
         syn::Data::Enum(_) => panic!("Enums not supported"),
@@ -46,10 +87,27 @@ pub fn from_dataframe_row_derive_impl(ast: DeriveInput) -> TokenStream {
         Span::call_site(),
     );
 
-    let fields_list: Vec<_> = struct_data
+    let getter_struct_ident = Ident::new(
+        format!("{struct_ident_str}{ROW_GETTER_NAME}").as_str(),
+        Span::call_site(),
+    );
+
+    let (row_index_fields, column_fields): (Vec<_>, Vec<_>) = struct_data
         .fields
         .iter()
         .cloned()
+        .partition(|field| field.attrs.iter().any(|attr| attr.path().is_ident("row_index")));
+
+    let row_index_fields = row_index_fields
+        .into_iter()
+        .map(|field| RowIndexField {
+            ident: field.ident.expect("anonymous fields not supported"),
+            ty: field.ty,
+        })
+        .collect();
+
+    let fields_list: Vec<_> = column_fields
+        .into_iter()
         .map(create_iterator_struct_field_info)
         .collect();
 
@@ -63,10 +121,15 @@ pub fn from_dataframe_row_derive_impl(ast: DeriveInput) -> TokenStream {
 
     let ctx = Context {
         struct_ident,
+        struct_vis: ast.vis.clone(),
         builder_struct_ident,
         iter_struct_ident,
+        getter_struct_ident,
         fields_list,
+        row_index_fields,
         has_lifetime,
+        cast: struct_attrs.cast.is_set(),
+        filter: struct_attrs.filter,
     };
 
     let builder_struct = create_builder_struct(&ctx);
@@ -77,6 +140,8 @@ pub fn from_dataframe_row_derive_impl(ast: DeriveInput) -> TokenStream {
     let iterator_struct = create_iterator_struct(&ctx);
     let iterator_struct_impl = create_iterator_struct_impl(&ctx);
     let iterator_impl_for_iterator_struct = create_iterator_impl_for_iterator_struct(&ctx);
+    let getter_struct = create_getter_struct(&ctx);
+    let getter_struct_impl = create_getter_struct_impl(&ctx);
 
     let stream: TokenStream = quote! {
         #builder_struct
@@ -87,6 +152,8 @@ pub fn from_dataframe_row_derive_impl(ast: DeriveInput) -> TokenStream {
         #iterator_struct
         #iterator_struct_impl
         #iterator_impl_for_iterator_struct
+        #getter_struct
+        #getter_struct_impl
     };
 
     stream
@@ -158,7 +225,7 @@ fn create_builder_struct_column_name_builder_impl(ctx: &Context) -> proc_macro2:
     let builder_struct_ident = &ctx.builder_struct_ident;
 
     quote! {
-        impl<'a> ColumnNameBuilder<'a> for #builder_struct_ident<'a> {
+        impl<'a> ::polars_rows_iter::ColumnNameBuilder<'a> for #builder_struct_ident<'a> {
             fn build(self) -> std::collections::HashMap<&'a str, &'a str> {
                 self.columns
             }
@@ -205,24 +272,58 @@ fn create_from_dataframe_row_trait_impl(ctx: &Context, generics: &Generics) -> p
     let impl_generics = create_impl_generics(generics, &lifetime);
 
     let iter_create_list = ctx.fields_list.iter().map(|f| {
-        let field_name = f.ident.to_string();
         let ident_iter = &f.iter_ident;
-        let ident_dtype = &f.dtype_ident;
-        let column_name = &f.column_name_expr;
-        let field_type = remove_lifetime(f.inner_ty.clone());
+        let ident_column = &f.column_ident;
+        let ident_resolved = create_resolved_ident(f);
+        let ident_null_policy = &f.null_policy_ident;
+        let null_policy = match f.on_null {
+            Some(on_null) => quote! { #on_null },
+            None => quote! { null_policy },
+        };
+        let null_policy_init = match f.is_optional {
+            true => quote! {},
+            false => quote! {
+                let #ident_null_policy = #null_policy;
+                if #ident_null_policy == ::polars_rows_iter::NullPolicy::SkipRow {
+                    skip_null_columns.push(#ident_column.column());
+                }
+            },
+        };
         quote! {
-            let column = dataframe.column(#columns_param_ident.remove(#field_name).unwrap_or(#column_name))?;
-            let #ident_iter = <#field_type as IterFromColumn<#lifetime>>::create_iter(column)?;
-            let #ident_dtype = column.dtype().clone();
+            let (#ident_column, #ident_iter) = #ident_resolved.expect("schema was resolved");
+            #null_policy_init
+        }
+    });
+
+    let null_check_list = ctx.fields_list.iter().filter(|f| !f.is_optional).map(|f| {
+        let ident_column = &f.column_ident;
+        let ident_null_policy = &f.null_policy_ident;
+        quote! {
+            // a mandatory field fails for every selected row if its column has only null values in them, so fail early
+            let all_null_row = match #ident_null_policy {
+                ::polars_rows_iter::NullPolicy::Error => cursor.all_null_row(#ident_column.column()),
+                _ => None,
+            };
+            if let Some(row) = all_null_row {
+                return Err(::polars_rows_iter::RowsIterError::UnexpectedNull {
+                    column: #ident_column.name().to_string(),
+                    row,
+                }.into());
+            }
         }
     });
 
     let struct_ident = &ctx.struct_ident;
     let iter_struct_ident = &ctx.iter_struct_ident;
+    let getter_struct_ident = &ctx.getter_struct_ident;
     let iter_ident_list = ctx.fields_list.iter().map(|f| {
         let ident_iter = &f.iter_ident;
-        let ident_dtype = &f.dtype_ident;
-        quote! { #ident_iter, #ident_dtype }
+        let ident_column = &f.column_ident;
+        let ident_null_policy = &f.null_policy_ident;
+        match f.is_optional {
+            true => quote! { #ident_iter, #ident_column },
+            false => quote! { #ident_iter, #ident_column, #ident_null_policy },
+        }
     });
 
     let struct_ident = match ctx.has_lifetime {
@@ -230,27 +331,187 @@ fn create_from_dataframe_row_trait_impl(ctx: &Context, generics: &Generics) -> p
         false => quote! { #struct_ident },
     };
 
-    let iter_struct_ident = match ctx.has_lifetime {
-        true => quote! { #iter_struct_ident::<#lifetime> },
-        false => quote! { #iter_struct_ident },
+    let builder_struct_ident = &ctx.builder_struct_ident;
+
+    let mandatory_column_name_list = ctx
+        .fields_list
+        .iter()
+        .filter(|f| !f.is_optional && matches!(f.on_null, None | Some(OnNull::Error)))
+        .map(|f| {
+            let field_name = f.ident.to_string();
+            let column_name = &f.column_name_expr;
+            quote! { #columns_param_ident.get(#field_name).copied().unwrap_or(#column_name) }
+        });
+
+    let resolve_list = ctx.fields_list.iter().map(|f| {
+        let field_name = f.ident.to_string();
+        let ident_resolved = create_resolved_ident(f);
+        let column_name = &f.column_name_expr;
+        let field_type = remove_lifetime(f.inner_ty.clone());
+        quote! {
+            let #ident_resolved = schema_resolver.resolve::<#field_type>(
+                #field_name,
+                #columns_param_ident.remove(#field_name).unwrap_or(#column_name),
+            );
+        }
+    });
+
+    let (filter_resolve, filter_push, filter_column_fn, filter_expected_column, filter_validate) = match &ctx.filter {
+        Some(filter_column) => (
+            quote! { let resolved_filter = schema_resolver.resolve_filter(#filter_column); },
+            quote! { filters.push(resolved_filter.expect("schema was resolved")); },
+            quote! {
+                fn filter_column() -> Option<&'static str> {
+                    Some(#filter_column)
+                }
+            },
+            quote! {
+                ::polars_rows_iter::ExpectedColumn {
+                    field: ::polars_rows_iter::FILTER_FIELD,
+                    column: #filter_column,
+                    dtypes: vec![polars::prelude::DataType::Boolean],
+                    nullable: true,
+                },
+            },
+            quote! { schema_validator.resolve_filter(#filter_column); },
+        ),
+        None => (quote! {}, quote! {}, quote! {}, quote! {}, quote! {}),
     };
 
-    let builder_struct_ident = &ctx.builder_struct_ident;
+    let resolve_list = resolve_list.collect_vec();
+    let create_resolve_list = |cast: TokenStream, filter_resolve: &TokenStream| {
+        quote! {
+            let mut schema_resolver = ::polars_rows_iter::SchemaResolver::new(dataframe, #cast);
+            #(#resolve_list)*
+            #filter_resolve
+            schema_resolver.finish()?;
+        }
+    };
+
+    let cast = ctx.cast;
+    // the accessor reads single rows by index, the filter column does not apply
+    let iter_resolve_list = create_resolve_list(quote! { options.cast || #cast }, &filter_resolve);
+    let accessor_resolve_list = create_resolve_list(quote! { #cast }, &quote! {});
+
+    let accessor_column_list = ctx.fields_list.iter().map(|f| {
+        let ident_iter = &f.iter_ident;
+        let ident_column = &f.column_ident;
+        let ident_resolved = create_resolved_ident(f);
+        quote! {
+            let (#ident_column, #ident_iter) = #ident_resolved.expect("schema was resolved");
+        }
+    });
+
+    let getter_ident_list = ctx.fields_list.iter().map(|f| {
+        let ident_iter = &f.iter_ident;
+        let ident_column = &f.column_ident;
+        quote! { #ident_iter, #ident_column }
+    });
+
+    let expected_column_list = ctx.fields_list.iter().map(|f| {
+        let field_name = f.ident.to_string();
+        let column_name = &f.column_name_expr;
+        let field_type = remove_lifetime(f.inner_ty.clone());
+        let nullable = match (f.is_optional, f.on_null) {
+            (true, _) => true,
+            (false, Some(on_null)) => on_null != OnNull::Error,
+            (false, None) => false,
+        };
+        quote! {
+            ::polars_rows_iter::ExpectedColumn {
+                field: #field_name,
+                column: #column_name,
+                dtypes: <#field_type as ::polars_rows_iter::IterFromColumn<#lifetime>>::accepted_dtypes(),
+                nullable: #nullable,
+            }
+        }
+    });
+
+    let validate_list = ctx.fields_list.iter().map(|f| {
+        let field_name = f.ident.to_string();
+        let column_name = &f.column_name_expr;
+        let field_type = remove_lifetime(f.inner_ty.clone());
+        quote! {
+            schema_validator.resolve::<#field_type>(#field_name, #column_name);
+        }
+    });
 
     quote::quote! {
         #[automatically_derived]
-        impl #impl_generics FromDataFrameRow #lifetime_generics for #struct_ident {
+        impl #impl_generics ::polars_rows_iter::FromDataFrameRow #lifetime_generics for #struct_ident {
             type Builder = #builder_struct_ident #lifetime_generics;
-            fn from_dataframe(
+            type Iter = #iter_struct_ident<#lifetime>;
+            type Getter = #getter_struct_ident<#lifetime>;
+
+            fn from_dataframe_with_filter(
                 dataframe: & #lifetime polars::prelude::DataFrame,
-                mut #columns_param_ident: std::collections::HashMap<&str, &str>
-            ) -> polars::prelude::PolarsResult<Box<dyn Iterator<Item = polars::prelude::PolarsResult<Self>> + #lifetime>>
+                mut #columns_param_ident: std::collections::HashMap<&str, &str>,
+                options: ::polars_rows_iter::RowsIterOptions,
+                filter: Option<&polars::prelude::BooleanChunked>,
+            ) -> polars::prelude::PolarsResult<Self::Iter>
                 where
                     Self: Sized
             {
+                #[allow(unused_variables)]
+                let null_policy = options.null_policy;
+                #[allow(unused_mut)]
+                let mut skip_null_columns: Vec<&polars::prelude::Column> = vec![];
+
+                #iter_resolve_list
+
                 #(#iter_create_list)*
 
-                Ok(Box::new(#iter_struct_ident { #(#iter_ident_list,)* }))
+                #[allow(unused_mut)]
+                let mut filters: Vec<&polars::prelude::BooleanChunked> = filter.into_iter().collect();
+                #filter_push
+
+                let cursor = ::polars_rows_iter::RowCursor::selecting_rows(dataframe.height(), &skip_null_columns, &filters)?
+                    .with_row_range(options.row_range)?
+                    .with_row_offset(options.row_offset);
+
+                #(#null_check_list)*
+
+                Ok(#iter_struct_ident {
+                    #(#iter_ident_list,)*
+                    cursor,
+                    _dataframe: std::marker::PhantomData,
+                })
+            }
+
+            fn create_accessor(
+                dataframe: & #lifetime polars::prelude::DataFrame,
+                mut #columns_param_ident: std::collections::HashMap<&str, &str>
+            ) -> polars::prelude::PolarsResult<::polars_rows_iter::RowAccessor<#lifetime, Self>>
+                where
+                    Self: Sized
+            {
+                #accessor_resolve_list
+
+                #(#accessor_column_list)*
+
+                let getter = #getter_struct_ident {
+                    #(#getter_ident_list,)*
+                    _dataframe: std::marker::PhantomData,
+                };
+
+                Ok(::polars_rows_iter::RowAccessor::new(dataframe.height(), getter))
+            }
+
+            fn get_mandatory_column_names<'c>(#columns_param_ident: &std::collections::HashMap<&str, &'c str>) -> Vec<&'c str> {
+                vec![#(#mandatory_column_name_list,)*]
+            }
+
+            #filter_column_fn
+
+            fn expected_schema() -> Vec<::polars_rows_iter::ExpectedColumn> {
+                vec![#(#expected_column_list,)* #filter_expected_column]
+            }
+
+            fn validate_schema(schema: &polars::prelude::Schema) -> Result<::polars_rows_iter::ResolvedColumns, ::polars_rows_iter::RowsIterError> {
+                let mut schema_validator = ::polars_rows_iter::SchemaValidator::new(schema, #cast);
+                #(#validate_list)*
+                #filter_validate
+                schema_validator.finish()
             }
 
             fn create_builder() -> #builder_struct_ident #lifetime_generics {
@@ -271,31 +532,70 @@ fn create_iterator_struct_field_info(mut field: Field) -> FieldInfo {
     let name = ident.to_string();
 
     let iter_ident = Ident::new(format!("{name}_iter").as_str(), Span::call_site());
-    let dtype_ident = Ident::new(format!("{name}_dtype").as_str(), Span::call_site());
+    let column_ident = Ident::new(format!("{name}_column").as_str(), Span::call_site());
+    let null_policy_ident = Ident::new(format!("{name}_null_policy").as_str(), Span::call_site());
     let ty = field.ty.clone();
 
     let attrs: ColumnFieldAttributes = deluxe::extract_attributes(&mut field).unwrap();
 
-    let column_name_expr = match attrs.0.len() {
+    let (on_null_exprs, column_name_exprs): (Vec<_>, Vec<_>) =
+        attrs.0.into_iter().partition(|expr| is_assignment_to(expr, "on_null"));
+
+    let column_name_expr = match column_name_exprs.len() {
         0 => Expr::Lit(ExprLit {
             attrs: vec![],
             lit: syn::Lit::Str(LitStr::new(&name, field.span())),
         }),
-        1 => attrs.0[0].clone(),
+        1 => column_name_exprs[0].clone(),
         _ => panic!("Field '{name}' can have only one column name"),
     };
 
     let mut is_optional = false;
     let inner_ty = get_inner_type_from_options(ty.clone(), &mut is_optional);
 
+    let on_null = match on_null_exprs.as_slice() {
+        [] => None,
+        [Expr::Assign(assign)] if !is_optional => Some(parse_null_policy(&name, &assign.right)),
+        [_] => panic!("Field '{name}' is optional, 'on_null' is only supported for mandatory fields"),
+        _ => panic!("Field '{name}' can have only one 'on_null' policy"),
+    };
+
     FieldInfo {
         name,
         ident,
         iter_ident,
-        dtype_ident,
+        column_ident,
+        null_policy_ident,
         inner_ty,
         is_optional,
         column_name_expr,
+        on_null,
+    }
+}
+
+fn is_assignment_to(expr: &Expr, name: &str) -> bool {
+    match expr {
+        Expr::Assign(assign) => matches!(assign.left.as_ref(), Expr::Path(path) if path.path.is_ident(name)),
+        _ => false,
+    }
+}
+
+fn parse_null_policy(field_name: &str, expr: &Expr) -> OnNull {
+    let policy = match expr {
+        Expr::Lit(ExprLit {
+            lit: syn::Lit::Str(policy),
+            ..
+        }) => policy.value(),
+        _ => panic!("Field '{field_name}' has an invalid 'on_null' policy, expected a string literal"),
+    };
+
+    match policy.as_str() {
+        "error" => OnNull::Error,
+        "skip" => OnNull::Skip,
+        "default" => OnNull::Default,
+        _ => panic!(
+            "Field '{field_name}' has unknown 'on_null' policy '{policy}', expected 'error', 'skip' or 'default'"
+        ),
     }
 }
 
@@ -324,13 +624,29 @@ fn get_inner_type_from_options(ty: Type, is_optional: &mut bool) -> Type {
     }
 }
 
-fn create_iterator_struct_field(field_info: &FieldInfo, lifetime: &LifetimeParam) -> proc_macro2::TokenStream {
+/// Column iterator and column of a field, shared by the row iterator and the row getter struct
+fn create_column_struct_fields(field_info: &FieldInfo, lifetime: &LifetimeParam) -> proc_macro2::TokenStream {
     let ident = &field_info.iter_ident;
-    let dtype_ident = &field_info.dtype_ident;
+    let column_ident = &field_info.column_ident;
     let ty = coerce_lifetime(field_info.inner_ty.clone(), lifetime);
     quote! {
-        #ident : Box<dyn Iterator<Item = Option<<#ty as IterFromColumn<#lifetime>>::RawInner>> + #lifetime>,
-        #dtype_ident: polars::prelude::DataType,
+        #ident: <#ty as ::polars_rows_iter::IterFromColumn<#lifetime>>::Iter,
+        #column_ident: ::polars_rows_iter::IterColumn<#lifetime>,
+    }
+}
+
+fn create_iterator_struct_field(field_info: &FieldInfo, lifetime: &LifetimeParam) -> proc_macro2::TokenStream {
+    let column_fields = create_column_struct_fields(field_info, lifetime);
+    let null_policy = match field_info.is_optional {
+        true => quote! {},
+        false => {
+            let null_policy_ident = &field_info.null_policy_ident;
+            quote! { #null_policy_ident: ::polars_rows_iter::NullPolicy, }
+        }
+    };
+    quote! {
+        #column_fields
+        #null_policy
     }
 }
 
@@ -343,11 +659,18 @@ fn create_iterator_struct(ctx: &Context) -> proc_macro2::TokenStream {
         .map(|field_info| create_iterator_struct_field(field_info, &lifetime));
 
     let iter_struct_ident = &ctx.iter_struct_ident;
+    let struct_vis = &ctx.struct_vis;
+    let doc = format!(" Row iterator over a dataframe yielding [`{}`] rows", ctx.struct_ident);
 
     quote! {
+        #[doc = #doc]
         #[automatically_derived]
-        struct #iter_struct_ident <#lifetime> {
+        #[derive(Clone)]
+        #struct_vis struct #iter_struct_ident <#lifetime> {
             #(#fields)*
+            cursor: ::polars_rows_iter::RowCursor,
+            // uses the lifetime for row structs with only `#[row_index]` fields
+            _dataframe: std::marker::PhantomData<&#lifetime polars::prelude::DataFrame>,
         }
     }
 }
@@ -355,24 +678,74 @@ fn create_iterator_struct(ctx: &Context) -> proc_macro2::TokenStream {
 fn create_iterator_struct_impl(ctx: &Context) -> proc_macro2::TokenStream {
     let lifetime = create_lifetime_param("a");
 
-    let fn_params = ctx.fields_list.iter().map(|field_info| {
+    let fn_params = ctx
+        .fields_list
+        .iter()
+        .map(|field_info| {
+            let ident = &field_info.ident;
+            let field_type = coerce_lifetime(field_info.inner_ty.clone(), &lifetime);
+            quote! { #ident: Option<<#field_type as ::polars_rows_iter::IterFromColumn<#lifetime>>::RawInner> }
+        })
+        .collect_vec();
+
+    let iter_value_source = |field_info: &FieldInfo| {
+        let ident_column = &field_info.column_ident;
+        let ident_null_policy = &field_info.null_policy_ident;
+        FieldValueSource {
+            null_policy: quote! { self.#ident_null_policy },
+            column_name: quote! { self.#ident_column.name() },
+            dtype: quote! { self.#ident_column.dtype() },
+            row: quote! { row },
+        }
+    };
+
+    let assignments = ctx.fields_list.iter().map(|field_info| {
         let ident = &field_info.ident;
-        let field_type = coerce_lifetime(field_info.inner_ty.clone(), &lifetime);
-        quote! { #ident: Option<<#field_type as IterFromColumn<#lifetime>>::RawInner> }
+        let value = create_field_value(
+            field_info,
+            &lifetime,
+            &quote! { #ident },
+            &iter_value_source(field_info),
+        );
+        quote! { #ident: #value }
     });
 
-    let assignments = ctx.fields_list.iter().map(|field_info| {
+    let updates = ctx.fields_list.iter().map(|field_info| {
         let ident = &field_info.ident;
-        let ident_dtype = &field_info.dtype_ident;
-        let field_type = coerce_lifetime(field_info.inner_ty.clone(), &lifetime);
-        let column_name = &field_info.column_name_expr;
+        create_field_update(
+            field_info,
+            &lifetime,
+            &quote! { #ident },
+            &iter_value_source(field_info),
+        )
+    });
 
-        match field_info.is_optional {
-            true => quote! { #ident: <Option<#field_type> as IterFromColumn<#lifetime>>::get_value(#ident, #column_name, &self.#ident_dtype)? },
-            false => quote! { #ident: <#field_type as IterFromColumn<#lifetime>>::get_value(#ident, #column_name, &self.#ident_dtype)? },
-        }
+    let row_index_assignments = create_row_index_assignments(ctx, quote! { row });
+    let row_index_updates = ctx.row_index_fields.iter().map(|field| {
+        let ident = &field.ident;
+        let value = create_row_index_value(field, &quote! { row });
+        quote! { target.#ident = #value }
     });
 
+    let value_idents = ctx
+        .fields_list
+        .iter()
+        .map(|f| (create_value_ident(f), &f.iter_ident))
+        .collect_vec();
+
+    let nth_value_list = value_idents
+        .iter()
+        .map(|(value_ident, iter_ident)| {
+            quote! { let #value_ident = self.#iter_ident.nth(skip)? }
+        })
+        .collect_vec();
+
+    let nth_back_value_list = value_idents.iter().map(|(value_ident, iter_ident)| {
+        quote! { let #value_ident = self.#iter_ident.nth_back(skip)? }
+    });
+
+    let value_ident_list = value_idents.iter().map(|(value_ident, _)| value_ident).collect_vec();
+
     let struct_ident = &ctx.struct_ident;
     let iter_struct_ident = &ctx.iter_struct_ident;
 
@@ -387,18 +760,247 @@ fn create_iterator_struct_impl(ctx: &Context) -> proc_macro2::TokenStream {
             #[allow(clippy::too_many_arguments)]
             fn create(
                 &self,
+                row: usize,
                 #(#fn_params,)*
-            ) -> polars::prelude::PolarsResult<#struct_ident_with_lifetime_if_nec> {
+            ) -> Result<#struct_ident_with_lifetime_if_nec, ::polars_rows_iter::RowsIterError> {
 
                 Ok(#struct_ident {
                     #(#assignments,)*
+                    #(#row_index_assignments,)*
                 })
 
             }
+
+            #[allow(clippy::too_many_arguments)]
+            fn update_row(
+                &self,
+                target: &mut #struct_ident_with_lifetime_if_nec,
+                row: usize,
+                #(#fn_params,)*
+            ) -> Result<(), ::polars_rows_iter::RowsIterError> {
+                #(#updates;)*
+                #(#row_index_updates;)*
+                Ok(())
+            }
+
+            #[inline]
+            fn try_nth(&mut self, n: usize) -> Option<Result<#struct_ident_with_lifetime_if_nec, ::polars_rows_iter::RowsIterError>> {
+                let (row, skip) = self.cursor.nth(n)?;
+
+                #(#nth_value_list;)*
+
+                Some(self.create(row, #(#value_ident_list,)*))
+            }
+
+            #[inline]
+            fn try_nth_back(&mut self, n: usize) -> Option<Result<#struct_ident_with_lifetime_if_nec, ::polars_rows_iter::RowsIterError>> {
+                let (row, skip) = self.cursor.nth_back(n)?;
+
+                #(#nth_back_value_list;)*
+
+                Some(self.create(row, #(#value_ident_list,)*))
+            }
+
+            #[inline]
+            fn try_update_next(&mut self, target: &mut #struct_ident_with_lifetime_if_nec) -> Option<Result<(), ::polars_rows_iter::RowsIterError>> {
+                let (row, skip) = self.cursor.nth(0)?;
+
+                #(#nth_value_list;)*
+
+                Some(self.update_row(target, row, #(#value_ident_list,)*))
+            }
         }
     }
 }
 
+fn create_getter_struct(ctx: &Context) -> proc_macro2::TokenStream {
+    let lifetime = create_lifetime_param("a");
+
+    let fields = ctx
+        .fields_list
+        .iter()
+        .map(|field_info| create_column_struct_fields(field_info, &lifetime));
+
+    let getter_struct_ident = &ctx.getter_struct_ident;
+    let struct_vis = &ctx.struct_vis;
+    let doc = format!(
+        " Row getter of row accessors reading [`{}`] rows by index",
+        ctx.struct_ident
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[automatically_derived]
+        #struct_vis struct #getter_struct_ident <#lifetime> {
+            #(#fields)*
+            // uses the lifetime for row structs with only `#[row_index]` fields
+            _dataframe: std::marker::PhantomData<&#lifetime polars::prelude::DataFrame>,
+        }
+    }
+}
+
+fn create_getter_struct_impl(ctx: &Context) -> proc_macro2::TokenStream {
+    let lifetime = create_lifetime_param("a");
+
+    let struct_ident = &ctx.struct_ident;
+    let getter_struct_ident = &ctx.getter_struct_ident;
+
+    let assignment_list = ctx.fields_list.iter().map(|f| {
+        let ident = &f.ident;
+        let ident_iter = &f.iter_ident;
+        let ident_column = &f.column_ident;
+        let source = FieldValueSource {
+            // the accessor has no options, only the null policy of the field applies
+            null_policy: f.on_null.unwrap_or(OnNull::Error).into_token_stream(),
+            column_name: quote! { self.#ident_column.name() },
+            dtype: quote! { self.#ident_column.dtype() },
+            row: quote! { index },
+        };
+        let value = create_field_value(f, &lifetime, &quote! { value }, &source);
+        quote! {
+            #ident: {
+                let value = ::polars_rows_iter::ColumnIter::value_at(&self.#ident_iter, index);
+                #value
+            }
+        }
+    });
+
+    let row_index_list = create_row_index_assignments(ctx, quote! { index });
+
+    let row_struct_ident = match ctx.has_lifetime {
+        true => quote! { #struct_ident<#lifetime> },
+        false => quote! { #struct_ident },
+    };
+
+    quote! {
+        #[automatically_derived]
+        impl<#lifetime> ::polars_rows_iter::RowGetter for #getter_struct_ident<#lifetime> {
+            type Row = #row_struct_ident;
+
+            fn get_row(&self, index: usize) -> polars::prelude::PolarsResult<Self::Row> {
+                Ok(#struct_ident {
+                    #(#assignment_list,)*
+                    #(#row_index_list,)*
+                })
+            }
+        }
+    }
+}
+
+/// Expressions of the null policy, column name and dtype and the row index to convert a raw field value with, which
+/// differ between the row iterator and the row accessor
+struct FieldValueSource {
+    null_policy: TokenStream,
+    column_name: TokenStream,
+    dtype: TokenStream,
+    row: TokenStream,
+}
+
+/// Converts the raw value of a field into the field value, applying the null policy to None/null values of mandatory
+/// fields. Shared by the row iterator and the row accessor, so that both handle None/null values the same way.
+fn create_field_value(
+    field_info: &FieldInfo,
+    lifetime: &LifetimeParam,
+    value: &TokenStream,
+    source: &FieldValueSource,
+) -> TokenStream {
+    let field_type = coerce_lifetime(field_info.inner_ty.clone(), lifetime);
+    let FieldValueSource {
+        null_policy,
+        column_name,
+        dtype,
+        row,
+    } = source;
+
+    match field_info.is_optional {
+        true => {
+            quote! { <Option<#field_type> as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_row_value(#value, #column_name, #dtype, #row)? }
+        }
+        false => {
+            let default_value = create_default_value(field_info, lifetime, source);
+            quote! {
+                match (#value, #null_policy) {
+                    (None, ::polars_rows_iter::NullPolicy::UseDefault) => #default_value,
+                    (value, _) => <#field_type as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_row_value(value, #column_name, #dtype, #row)?,
+                }
+            }
+        }
+    }
+}
+
+/// Overwrites a field of the `target` row with the converted raw value like `create_field_value()`
+fn create_field_update(
+    field_info: &FieldInfo,
+    lifetime: &LifetimeParam,
+    value: &TokenStream,
+    source: &FieldValueSource,
+) -> TokenStream {
+    let ident = &field_info.ident;
+    let field_type = coerce_lifetime(field_info.inner_ty.clone(), lifetime);
+    let FieldValueSource {
+        null_policy,
+        column_name,
+        dtype,
+        row,
+    } = source;
+
+    match field_info.is_optional {
+        true => {
+            quote! { <Option<#field_type> as ::polars_rows_iter::IterFromColumn<#lifetime>>::update_row_value(&mut target.#ident, #value, #column_name, #dtype, #row)? }
+        }
+        false => {
+            let default_value = create_default_value(field_info, lifetime, source);
+            quote! {
+                match (#value, #null_policy) {
+                    (None, ::polars_rows_iter::NullPolicy::UseDefault) => target.#ident = #default_value,
+                    (value, _) => <#field_type as ::polars_rows_iter::IterFromColumn<#lifetime>>::update_row_value(&mut target.#ident, value, #column_name, #dtype, #row)?,
+                }
+            }
+        }
+    }
+}
+
+/// Value of a mandatory field for a None/null value with `NullPolicy::UseDefault`. Only fields with
+/// `on_null = "default"` require the field type to implement `Default`, other fields get the null policy from the
+/// iterator options and use `IterFromColumn::default_value()`, failing like `NullPolicy::Error` without one.
+fn create_default_value(field_info: &FieldInfo, lifetime: &LifetimeParam, source: &FieldValueSource) -> TokenStream {
+    let field_type = coerce_lifetime(field_info.inner_ty.clone(), lifetime);
+    let FieldValueSource {
+        column_name,
+        dtype,
+        row,
+        ..
+    } = source;
+
+    match field_info.on_null {
+        Some(OnNull::Default) => quote! { Default::default() },
+        _ => quote! {
+            match <#field_type as ::polars_rows_iter::IterFromColumn<#lifetime>>::default_value() {
+                Some(value) => value,
+                None => <#field_type as ::polars_rows_iter::IterFromColumn<#lifetime>>::get_row_value(None, #column_name, #dtype, #row)?,
+            }
+        },
+    }
+}
+
+/// Struct field assignments `field: value` of the `#[row_index]` fields, for the row index in the `row` expression
+fn create_row_index_assignments(ctx: &Context, row: TokenStream) -> Vec<TokenStream> {
+    ctx.row_index_fields
+        .iter()
+        .map(|field| {
+            let ident = &field.ident;
+            let value = create_row_index_value(field, &row);
+            quote! { #ident: #value }
+        })
+        .collect_vec()
+}
+
+fn create_row_index_value(field: &RowIndexField, row: &TokenStream) -> TokenStream {
+    let ty = &field.ty;
+    let field_name = field.ident.to_string();
+    quote! { ::polars_rows_iter::row_index_value::<#ty>(#field_name, #row)? }
+}
+
 fn coerce_lifetime(ty: Type, lifetime: &LifetimeParam) -> Type {
     match ty {
         Type::Reference(type_reference) => Type::Reference(TypeReference {
@@ -419,28 +1021,22 @@ fn remove_lifetime(ty: Type) -> Type {
     }
 }
 
-fn create_iterator_impl_for_iterator_struct(ctx: &Context) -> proc_macro2::TokenStream {
-    let lifetime = create_lifetime_param("a");
-
-    let fields: Vec<_> = ctx
-        .fields_list
-        .iter()
-        .map(|f| {
-            (
-                Ident::new(
-                    format!("{field_name}_value", field_name = f.name).as_str(),
-                    Span::call_site(),
-                ),
-                &f.iter_ident,
-            )
-        })
-        .collect();
+fn create_resolved_ident(field_info: &FieldInfo) -> Ident {
+    Ident::new(
+        format!("{field_name}_resolved", field_name = field_info.name).as_str(),
+        Span::call_site(),
+    )
+}
 
-    let next_value_list = fields.iter().map(|(value_ident, iter_ident)| {
-        quote! { let #value_ident = self.#iter_ident.next()? }
-    });
+fn create_value_ident(field_info: &FieldInfo) -> Ident {
+    Ident::new(
+        format!("{field_name}_value", field_name = field_info.name).as_str(),
+        Span::call_site(),
+    )
+}
 
-    let value_ident_list = fields.iter().map(|(value_ident, _)| value_ident);
+fn create_iterator_impl_for_iterator_struct(ctx: &Context) -> proc_macro2::TokenStream {
+    let lifetime = create_lifetime_param("a");
 
     let struct_ident = &ctx.struct_ident;
     let iter_struct_ident = &ctx.iter_struct_ident;
@@ -454,10 +1050,74 @@ fn create_iterator_impl_for_iterator_struct(ctx: &Context) -> proc_macro2::Token
         impl<#lifetime> Iterator for #iter_struct_ident<#lifetime> {
             type Item = polars::prelude::PolarsResult<#struct_ident>;
 
+            #[inline]
             fn next(&mut self) -> Option<Self::Item> {
-                #(#next_value_list;)*
+                self.nth(0)
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                Some(self.try_nth(n)?.map_err(polars::prelude::PolarsError::from))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.cursor.len();
+                (len, Some(len))
+            }
+        }
+
+        impl<#lifetime> DoubleEndedIterator for #iter_struct_ident<#lifetime> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.nth_back(0)
+            }
+
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                Some(self.try_nth_back(n)?.map_err(polars::prelude::PolarsError::from))
+            }
+        }
+
+        impl<#lifetime> ExactSizeIterator for #iter_struct_ident<#lifetime> {}
+
+        impl<#lifetime> ::polars_rows_iter::RowIndexIterator for #iter_struct_ident<#lifetime> {
+            #[inline]
+            fn next_row_index(&self) -> Option<usize> {
+                self.cursor.next_row_index()
+            }
+        }
+
+        impl<#lifetime> ::polars_rows_iter::SelectRowsIterator for #iter_struct_ident<#lifetime> {
+            fn select_rows(&self, rows: impl IntoIterator<Item = usize>) -> Self {
+                Self {
+                    cursor: self.cursor.select_rows(rows),
+                    ..self.clone()
+                }
+            }
+        }
+
+        impl<#lifetime> ::polars_rows_iter::SeekRowsIterator for #iter_struct_ident<#lifetime> {
+            #[inline]
+            fn skip_rows(&mut self, n: usize) {
+                self.cursor.skip(n)
+            }
+
+            #[inline]
+            fn skip_rows_back(&mut self, n: usize) {
+                self.cursor.skip_back(n)
+            }
+        }
+
+        impl<#lifetime> ::polars_rows_iter::TryRowsIterator for #iter_struct_ident<#lifetime> {
+            type Row = #struct_ident;
+
+            #[inline]
+            fn try_next(&mut self) -> Option<Result<Self::Row, ::polars_rows_iter::RowsIterError>> {
+                self.try_nth(0)
+            }
 
-                Some(self.create(#(#value_ident_list,)*))
+            #[inline]
+            fn try_next_into(&mut self, row: &mut Self::Row) -> Option<Result<(), ::polars_rows_iter::RowsIterError>> {
+                self.try_update_next(row)
             }
         }
     }
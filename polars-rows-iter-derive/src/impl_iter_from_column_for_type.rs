@@ -1,11 +1,29 @@
 use quote::quote;
 
 pub fn create_impl_for(ident: syn::Ident) -> proc_macro::TokenStream {
+    let polars_type = get_polars_type(&ident);
+    let dtype = get_dtype(&ident);
+
     quote! {
         impl<'a> IterFromColumn<'a> for #ident {
             type RawInner = #ident;
-            fn create_iter(column: &'a polars::prelude::Column) -> polars::prelude::PolarsResult<Box<dyn Iterator<Item = Option<#ident>> + 'a>> {
-                Ok(Box::new(column.#ident()?.iter()))
+            type Iter = ChunkedArrayIter<'a, polars::prelude::#polars_type>;
+
+            fn create_iter(column: &'a polars::prelude::Column) -> polars::prelude::PolarsResult<Self::Iter> {
+                Ok(ChunkedArrayIter::new(column.#ident()?))
+            }
+
+            fn accepted_dtypes() -> Vec<polars::prelude::DataType> {
+                vec![polars::prelude::DataType::#dtype]
+            }
+
+            fn create_cast_iter(column: &polars::prelude::Column) -> Option<Self::Iter> {
+                Some(ChunkedArrayIter::owned(column.#ident().ok()?))
+            }
+
+            #[inline]
+            fn get_raw(column: &'a polars::prelude::Column, index: usize) -> polars::prelude::PolarsResult<Option<#ident>> {
+                Ok(column.#ident()?.get(index))
             }
 
             #[inline]
@@ -15,13 +33,32 @@ pub fn create_impl_for(ident: syn::Ident) -> proc_macro::TokenStream {
             {
                 polars_value.ok_or_else(|| <#ident as IterFromColumn<'a>>::unexpected_null_value_error(column_name))
             }
+
+            #[inline]
+            fn default_value() -> Option<Self> {
+                Some(Self::default())
+            }
         }
 
         impl<'a> IterFromColumn<'a> for Option<#ident> {
             type RawInner = #ident;
-            fn create_iter(column: &'a polars::prelude::Column) -> polars::prelude::PolarsResult<Box<dyn Iterator<Item = Option<#ident>> + 'a>> {
-                let iter = Box::new(column.#ident()?.iter());
-                Ok(iter)
+            type Iter = ChunkedArrayIter<'a, polars::prelude::#polars_type>;
+
+            fn create_iter(column: &'a polars::prelude::Column) -> polars::prelude::PolarsResult<Self::Iter> {
+                Ok(ChunkedArrayIter::new(column.#ident()?))
+            }
+
+            fn accepted_dtypes() -> Vec<polars::prelude::DataType> {
+                vec![polars::prelude::DataType::#dtype]
+            }
+
+            fn create_cast_iter(column: &polars::prelude::Column) -> Option<Self::Iter> {
+                Some(ChunkedArrayIter::owned(column.#ident().ok()?))
+            }
+
+            #[inline]
+            fn get_raw(column: &'a polars::prelude::Column, index: usize) -> polars::prelude::PolarsResult<Option<#ident>> {
+                Ok(column.#ident()?.get(index))
             }
 
             #[inline]
@@ -35,3 +72,41 @@ pub fn create_impl_for(ident: syn::Ident) -> proc_macro::TokenStream {
     }
     .into()
 }
+
+fn get_polars_type(ident: &syn::Ident) -> syn::Ident {
+    let polars_type = match ident.to_string().as_str() {
+        "bool" => "BooleanType",
+        "i8" => "Int8Type",
+        "i16" => "Int16Type",
+        "i32" => "Int32Type",
+        "i64" => "Int64Type",
+        "u8" => "UInt8Type",
+        "u16" => "UInt16Type",
+        "u32" => "UInt32Type",
+        "u64" => "UInt64Type",
+        "f32" => "Float32Type",
+        "f64" => "Float64Type",
+        other => panic!("No polars type known for '{other}'"),
+    };
+
+    syn::Ident::new(polars_type, ident.span())
+}
+
+fn get_dtype(ident: &syn::Ident) -> syn::Ident {
+    let dtype = match ident.to_string().as_str() {
+        "bool" => "Boolean",
+        "i8" => "Int8",
+        "i16" => "Int16",
+        "i32" => "Int32",
+        "i64" => "Int64",
+        "u8" => "UInt8",
+        "u16" => "UInt16",
+        "u32" => "UInt32",
+        "u64" => "UInt64",
+        "f32" => "Float32",
+        "f64" => "Float64",
+        other => panic!("No polars dtype known for '{other}'"),
+    };
+
+    syn::Ident::new(dtype, ident.span())
+}
@@ -5,7 +5,7 @@
 mod from_dataframe_row_derive;
 mod impl_iter_from_column_for_type;
 
-#[proc_macro_derive(FromDataFrameRow, attributes(column))]
+#[proc_macro_derive(FromDataFrameRow, attributes(column, rows, row_index))]
 pub fn from_dataframe_row_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast: syn::DeriveInput = syn::parse2(input.into()).unwrap();
     from_dataframe_row_derive::from_dataframe_row_derive_impl(ast).into()
@@ -0,0 +1,247 @@
+use std::{
+    fs::File,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use polars::prelude::*;
+
+use crate::{FromDataFrameRow, OwnedRowsIter, RowsIterOptions};
+
+/// Row iterator over a `LazyFrame`, which runs the query once on the streaming engine into a temporary Arrow IPC file
+/// and iterates over its rows in batches. The file is memory mapped, so only the batch being iterated needs to be held
+/// in memory, and the rows keep the order of the query. The file is created in `std::env::temp_dir()`, e.g. set by the
+/// `TMPDIR` environment variable on Unix, and deleted when the iterator is dropped. Rows need owned field types like
+/// `String`.
+pub struct BatchedRowsIter<T>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    // fields are dropped in declaration order, so the memory mapped dataframe is released before the file is deleted
+    batch: Option<OwnedRowsIter<T>>,
+    dataframe: DataFrame,
+    options: RowsIterOptions,
+    batch_size: usize,
+    offset: usize,
+    _spill_file: SpillFile,
+}
+
+impl<T> BatchedRowsIter<T>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    /// Validates the schema of the query before running it, so that schema errors are returned on creation without
+    /// waiting for the query
+    pub(crate) fn new(mut lazy_frame: LazyFrame, options: RowsIterOptions, batch_size: usize) -> PolarsResult<Self> {
+        polars_ensure!(batch_size > 0, InvalidOperation: "Batch size must be greater than 0");
+
+        T::validate_schema(&*lazy_frame.collect_schema()?)?;
+
+        let spill_file = SpillFile::new();
+        let target = SinkTarget::Path(Arc::new(spill_file.path.clone()));
+        lazy_frame
+            .sink_ipc(target, IpcWriterOptions::default(), None, SinkOptions::default())?
+            .collect_with_engine(Engine::Streaming)?;
+
+        // the record batches of the file are kept as chunks, a rechunk would copy the whole file into memory
+        let dataframe = IpcReader::new(File::open(&spill_file.path)?)
+            .memory_mapped(Some(spill_file.path.clone()))
+            .set_rechunk(false)
+            .finish()?;
+
+        let mut iter = Self {
+            batch: None,
+            dataframe,
+            options,
+            batch_size,
+            offset: 0,
+            _spill_file: spill_file,
+        };
+        iter.batch = iter.next_batch()?;
+
+        Ok(iter)
+    }
+
+    fn next_batch(&mut self) -> PolarsResult<Option<OwnedRowsIter<T>>> {
+        if self.offset >= self.dataframe.height() {
+            return Ok(None);
+        }
+
+        let dataframe = self.dataframe.slice(self.offset as i64, self.batch_size);
+
+        // row indices of the batch continue the ones of the previous batches
+        let options = RowsIterOptions {
+            row_offset: self.options.row_offset + self.offset,
+            ..self.options
+        };
+
+        self.offset += dataframe.height();
+
+        OwnedRowsIter::new(dataframe, options).map(Some)
+    }
+}
+
+impl<T> Iterator for BatchedRowsIter<T>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    type Item = PolarsResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(row) = self.batch.as_mut()?.next() {
+                return Some(row);
+            }
+
+            match self.next_batch() {
+                Ok(batch) => self.batch = batch,
+                Err(error) => {
+                    self.batch = None;
+                    return Some(Err(error));
+                }
+            }
+        }
+    }
+}
+
+/// Temporary file holding the result of the query, which is deleted on drop
+struct SpillFile {
+    path: PathBuf,
+}
+
+impl SpillFile {
+    fn new() -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let name = format!(
+            "polars-rows-iter-{}-{}.arrow",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+
+        Self {
+            path: std::env::temp_dir().join(name),
+        }
+    }
+}
+
+impl Drop for SpillFile {
+    fn drop(&mut self) {
+        // the file does not exist if the query failed before writing it
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::prelude::*;
+
+    use crate::*;
+
+    #[derive(Debug, PartialEq, FromDataFrameRow)]
+    struct TestRow {
+        id: i32,
+        name: String,
+    }
+
+    fn create_lazy_frame(height: i32) -> LazyFrame {
+        df!(
+            "id" => (0..height).collect::<Vec<_>>(),
+            "name" => (0..height).map(|id| format!("name {id}")).collect::<Vec<_>>(),
+            "unused" => (0..height).map(f64::from).collect::<Vec<_>>()
+        )
+        .unwrap()
+        .lazy()
+    }
+
+    #[test]
+    fn batched_iter_should_yield_rows_across_batch_boundaries() {
+        for height in [0, 1, 3, 4, 10] {
+            let rows = create_lazy_frame(height)
+                .rows_iter_batched::<TestRow>(3)
+                .unwrap()
+                .map(|row| row.unwrap())
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                (0..height).collect::<Vec<_>>(),
+                rows.iter().map(|row| row.id).collect::<Vec<_>>()
+            );
+            assert!(rows.iter().all(|row| row.name == format!("name {}", row.id)));
+        }
+    }
+
+    #[test]
+    fn batched_iter_should_keep_the_row_order_of_the_query() {
+        let lazy_frame =
+            create_lazy_frame(100).sort(["id"], SortMultipleOptions::default().with_order_descending(true));
+
+        let ids = lazy_frame
+            .rows_iter_batched::<TestRow>(7)
+            .unwrap()
+            .map(|row| row.unwrap().id)
+            .collect::<Vec<_>>();
+
+        assert_eq!((0..100).rev().collect::<Vec<_>>(), ids);
+    }
+
+    #[test]
+    fn batched_iter_should_delete_the_spill_file_on_drop() {
+        let iter = create_lazy_frame(10).rows_iter_batched::<TestRow>(4).unwrap();
+        let path = iter._spill_file.path.clone();
+        assert!(path.exists());
+
+        drop(iter);
+
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn batched_iter_should_fail_on_creation_for_invalid_schema_or_batch_size() {
+        let lazy_frame = create_lazy_frame(5).select([col("id")]);
+        let error = lazy_frame.rows_iter_batched::<TestRow>(2).err().unwrap();
+        assert!(matches!(error, PolarsError::ColumnNotFound(_)));
+
+        assert!(create_lazy_frame(5).rows_iter_batched::<TestRow>(0).is_err());
+    }
+
+    #[test]
+    fn batched_iter_should_collect_one_batch_at_a_time() {
+        let mut iter = create_lazy_frame(10).rows_iter_batched::<TestRow>(4).unwrap();
+
+        assert_eq!((4, 4), (iter.offset, iter.batch.as_ref().unwrap().len()));
+        assert_eq!(4, iter.nth(4).unwrap().unwrap().id);
+        assert_eq!((8, 3), (iter.offset, iter.batch.as_ref().unwrap().len()));
+    }
+
+    #[test]
+    fn batched_iter_should_be_movable_to_another_thread() {
+        let iter = create_lazy_frame(5).rows_iter_batched::<TestRow>(2).unwrap();
+
+        let count = std::thread::spawn(move || iter.count()).join().unwrap();
+
+        assert_eq!(5, count);
+    }
+
+    #[test]
+    fn batched_iter_should_continue_row_indices_across_batches() {
+        #[derive(Debug, FromDataFrameRow)]
+        struct IndexedRow {
+            #[row_index]
+            index: usize,
+            id: i32,
+        }
+
+        let rows = create_lazy_frame(5)
+            .rows_iter_batched::<IndexedRow>(2)
+            .unwrap()
+            .map(|row| row.unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(rows.iter().all(|row| row.index == row.id as usize));
+        assert_eq!(4, rows[4].index);
+    }
+}
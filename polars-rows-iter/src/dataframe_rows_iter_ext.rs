@@ -1,24 +1,106 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    ops::{Bound, RangeBounds},
+};
 
 use polars::prelude::*;
 
-use crate::{ColumnNameBuilder, FromDataFrameRow};
+use crate::{
+    ColumnNameBuilder, DynRowsIter, FromDataFrameRow, GroupRowsIter, IndexedRowsIter, NullColumn, NullPolicy,
+    OwnedRowsIter, RowAccessor, RowError, RowIndexIterator, RowsBatches, RowsIterError, RowsIterOptions,
+    TryRowsIterator,
+};
 
 pub trait DataframeRowsIterExt<'a> {
-    fn rows_iter<T>(&'a self) -> PolarsResult<Box<dyn Iterator<Item = PolarsResult<T>> + 'a>>
+    fn rows_iter<T>(&'a self) -> PolarsResult<T::Iter>
     where
         T: FromDataFrameRow<'a>;
 
     fn rows_iter_with_columns<T>(
         &'a self,
         build_fn: impl FnOnce(&mut T::Builder) -> &mut T::Builder,
-    ) -> PolarsResult<Box<dyn Iterator<Item = PolarsResult<T>> + 'a>>
+    ) -> PolarsResult<T::Iter>
+    where
+        T: FromDataFrameRow<'a>;
+
+    fn rows_iter_validated<T>(&'a self) -> PolarsResult<T::Iter>
+    where
+        T: FromDataFrameRow<'a>;
+
+    fn validate_nulls<T>(&'a self) -> Result<(), RowsIterError>
+    where
+        T: FromDataFrameRow<'a>;
+
+    fn rows_iter_indexed<T>(&'a self) -> PolarsResult<IndexedRowsIter<T::Iter>>
+    where
+        T: FromDataFrameRow<'a>;
+
+    fn rows_iter_indexed_with_offset<T>(&'a self, row_offset: usize) -> PolarsResult<IndexedRowsIter<T::Iter>>
+    where
+        T: FromDataFrameRow<'a>;
+
+    fn rows_iter_filtered<T>(&'a self, mask: &BooleanChunked) -> PolarsResult<T::Iter>
+    where
+        T: FromDataFrameRow<'a>;
+
+    fn rows_iter_range<T>(&'a self, range: impl RangeBounds<usize>) -> PolarsResult<T::Iter>
+    where
+        T: FromDataFrameRow<'a>;
+
+    fn rows_iter_with_null_policy<T>(&'a self, null_policy: NullPolicy) -> PolarsResult<T::Iter>
+    where
+        T: FromDataFrameRow<'a>;
+
+    fn rows_iter_cast<T>(&'a self) -> PolarsResult<T::Iter>
+    where
+        T: FromDataFrameRow<'a>;
+
+    fn rows_collect_with_errors<T>(&'a self, null_policy: NullPolicy) -> PolarsResult<(Vec<T>, Vec<RowError>)>
+    where
+        T: FromDataFrameRow<'a>;
+
+    fn rows_batches<T>(&'a self, batch_size: usize) -> PolarsResult<RowsBatches<'a, T>>
+    where
+        T: FromDataFrameRow<'a>;
+
+    fn for_each_row_mut<T>(&'a self, f: impl FnMut(&mut T)) -> PolarsResult<()>
+    where
+        T: FromDataFrameRow<'a>;
+
+    fn group_rows_iter<K, T>(
+        &'a self,
+        by: impl IntoIterator<Item = impl Into<PlSmallStr>>,
+    ) -> PolarsResult<GroupRowsIter<'a, K, T>>
+    where
+        K: FromDataFrameRow<'a>,
+        T: FromDataFrameRow<'a>;
+
+    fn into_rows_iter<T>(self) -> PolarsResult<OwnedRowsIter<T>>
+    where
+        T: for<'b> FromDataFrameRow<'b> + 'static;
+
+    fn dyn_rows_iter(&'a self) -> DynRowsIter<'a>;
+
+    fn row_accessor<T>(&'a self) -> PolarsResult<RowAccessor<'a, T>>
+    where
+        T: FromDataFrameRow<'a>;
+
+    fn row_accessor_with_columns<T>(
+        &'a self,
+        build_fn: impl FnOnce(&mut T::Builder) -> &mut T::Builder,
+    ) -> PolarsResult<RowAccessor<'a, T>>
+    where
+        T: FromDataFrameRow<'a>;
+
+    fn row_at<T>(&'a self, index: usize) -> PolarsResult<T>
     where
         T: FromDataFrameRow<'a>;
 }
 
 impl<'a> DataframeRowsIterExt<'a> for DataFrame {
-    /// Creates a row iterator for this DataFrame with static column names defined in row struct
+    /// Creates a row iterator for this DataFrame with static column names defined in row struct. A None/null value in
+    /// the column of a mandatory (non-`Option`) field fails the row, except if the column has only None/null values in
+    /// the rows to iterate, which fails already on iterator creation.
     /// ```rust
     /// use polars::prelude::*;
     /// use polars_rows_iter::*;
@@ -58,7 +140,7 @@ impl<'a> DataframeRowsIterExt<'a> for DataFrame {
     ///        ]
     ///    );
     /// ```
-    fn rows_iter<T>(&'a self) -> PolarsResult<Box<dyn Iterator<Item = PolarsResult<T>> + 'a>>
+    fn rows_iter<T>(&'a self) -> PolarsResult<T::Iter>
     where
         T: FromDataFrameRow<'a>,
     {
@@ -118,7 +200,7 @@ impl<'a> DataframeRowsIterExt<'a> for DataFrame {
     fn rows_iter_with_columns<T>(
         &'a self,
         build_fn: impl FnOnce(&mut T::Builder) -> &mut T::Builder,
-    ) -> PolarsResult<Box<dyn Iterator<Item = PolarsResult<T>> + 'a>>
+    ) -> PolarsResult<T::Iter>
     where
         T: FromDataFrameRow<'a>,
     {
@@ -130,68 +212,1312 @@ impl<'a> DataframeRowsIterExt<'a> for DataFrame {
 
         T::from_dataframe(self, columns)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    #![allow(dead_code)]
+    /// Creates a row iterator like `rows_iter()`, but checks all columns of mandatory (non-`Option`) fields for null
+    /// values upfront. Instead of failing at the first affected row during the iteration, a single error listing every
+    /// column containing null values, with its null count and first affected row index, is returned.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(FromDataFrameRow)]
+    ///struct MyRow {
+    ///    id: i32,
+    ///    value: f64,
+    ///}
+    ///
+    ///    let df = df!(
+    ///        "id" => [Some(1i32), None, None],
+    ///        "value" => [Some(1.0f64), Some(2.0), None]
+    ///    ).unwrap();
+    ///
+    ///    let error = df.rows_iter_validated::<MyRow>().err().unwrap();
+    ///
+    ///    assert!(error.to_string().contains("'id' (2 null values, first at row 1)"));
+    ///    assert!(error.to_string().contains("'value' (1 null values, first at row 2)"));
+    ///```
+    fn rows_iter_validated<T>(&'a self) -> PolarsResult<T::Iter>
+    where
+        T: FromDataFrameRow<'a>,
+    {
+        self.validate_nulls::<T>()?;
 
-    use polars::df;
+        T::from_dataframe(self, HashMap::new())
+    }
 
-    use crate::*;
+    /// Checks all columns of mandatory (non-`Option`) fields for null values like `rows_iter_validated()`, without
+    /// creating a row iterator. The returned `RowsIterError::UnexpectedNulls` lists every affected column with its
+    /// null count and first affected row index. Missing columns are not reported, they fail on iterator creation.
+    fn validate_nulls<T>(&'a self) -> Result<(), RowsIterError>
+    where
+        T: FromDataFrameRow<'a>,
+    {
+        let columns = HashMap::new();
+        let null_columns = T::get_mandatory_column_names(&columns)
+            .into_iter()
+            .filter_map(|name| self.column(name).ok())
+            .filter(|column| column.null_count() > 0)
+            .map(|column| NullColumn {
+                column: column.name().to_string(),
+                null_count: column.null_count(),
+                first_row: column
+                    .is_null()
+                    .iter()
+                    .position(|is_null| is_null == Some(true))
+                    .unwrap_or_default(),
+            })
+            .collect::<Vec<_>>();
 
-    #[derive(FromDataFrameRow)]
-    struct TestStruct {
-        x1: i32,
-        x2: i32,
+        match null_columns.is_empty() {
+            true => Ok(()),
+            false => Err(RowsIterError::UnexpectedNulls { columns: null_columns }),
+        }
     }
 
-    #[test]
-    fn rows_iter_should_return_error_when_given_column_not_available() {
-        let df = df!(
-            "y1" => [1i32, 2, 3],
-            "x2" => [1i32, 2, 3]
-        )
-        .unwrap();
+    /// Creates a row iterator yielding every row together with its index in this DataFrame. The index stays correct
+    /// when rows are skipped, e.g. by `#[column(on_null = "skip")]`, unlike with `enumerate()`. Alternatively the index
+    /// is read into a `usize` or `u32` field of the row struct with the `#[row_index]` attribute.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///struct MyRow {
+    ///    #[column(on_null = "skip")]
+    ///    value: f64,
+    ///}
+    ///
+    ///    let df = df!("value" => [Some(1.0f64), None, Some(3.0)]).unwrap();
+    ///
+    ///    let indices = df
+    ///        .rows_iter_indexed::<MyRow>()
+    ///        .unwrap()
+    ///        .map(|(index, _row)| index)
+    ///        .collect::<Vec<usize>>();
+    ///
+    ///    assert_eq!(indices, [0, 2]);
+    ///```
+    fn rows_iter_indexed<T>(&'a self) -> PolarsResult<IndexedRowsIter<T::Iter>>
+    where
+        T: FromDataFrameRow<'a>,
+    {
+        self.rows_iter::<T>().map(IndexedRowsIter::new)
+    }
 
-        let result = df.rows_iter::<TestStruct>();
+    /// Like `rows_iter_indexed()`, but adds the offset to the returned indices and to `#[row_index]` fields, e.g. the
+    /// offset of a slice to get the indices of the rows in the sliced DataFrame.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///struct MyRow {
+    ///    value: f64,
+    ///}
+    ///
+    ///    let df = df!("value" => [1.0f64, 2.0, 3.0, 4.0]).unwrap();
+    ///    let slice = df.slice(2, 2);
+    ///
+    ///    let indices = slice
+    ///        .rows_iter_indexed_with_offset::<MyRow>(2)
+    ///        .unwrap()
+    ///        .map(|(index, _row)| index)
+    ///        .collect::<Vec<usize>>();
+    ///
+    ///    assert_eq!(indices, [2, 3]);
+    ///```
+    fn rows_iter_indexed_with_offset<T>(&'a self, row_offset: usize) -> PolarsResult<IndexedRowsIter<T::Iter>>
+    where
+        T: FromDataFrameRow<'a>,
+    {
+        let options = RowsIterOptions {
+            row_offset,
+            ..Default::default()
+        };
+        T::from_dataframe_with_options(self, HashMap::new(), options).map(IndexedRowsIter::new)
+    }
 
-        assert!(result.is_err());
+    /// Creates a row iterator over the rows where the mask is true, without copying the columns like
+    /// `DataFrame::filter()`. The values of the other rows are skipped without being converted, so they cannot fail.
+    /// A boolean column of the DataFrame is used as filter for all iterators of a row struct with
+    /// `#[rows(filter = "column")]`.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///struct MyRow {
+    ///    id: i32,
+    ///}
+    ///
+    ///    let df = df!("id" => [1i32, 2, 3]).unwrap();
+    ///    let mask = df.column("id").unwrap().as_materialized_series().gt(1).unwrap();
+    ///
+    ///    let ids = df
+    ///        .rows_iter_filtered::<MyRow>(&mask)
+    ///        .unwrap()
+    ///        .map(|row| row.map(|row| row.id))
+    ///        .collect::<PolarsResult<Vec<i32>>>()
+    ///        .unwrap();
+    ///
+    ///    assert_eq!(ids, [2, 3]);
+    ///```
+    fn rows_iter_filtered<T>(&'a self, mask: &BooleanChunked) -> PolarsResult<T::Iter>
+    where
+        T: FromDataFrameRow<'a>,
+    {
+        T::from_dataframe_with_filter(self, HashMap::new(), RowsIterOptions::default(), Some(mask))
     }
 
-    #[test]
-    fn builder_should_build_hashmap_with_correct_entries() {
-        let mut builder = TestStruct::create_builder();
-        builder.x1("column_1").x2("column_2");
-        let columns = builder.build();
+    /// Creates a row iterator over the rows in the given range of row indices, e.g. for pagination. The column iterators
+    /// seek to the start of the range without reading the values before, and without copying the columns like
+    /// `DataFrame::slice()`. Returns an error if the range is out of bounds.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///struct MyRow {
+    ///    id: i32,
+    ///}
+    ///
+    ///    let df = df!("id" => [1i32, 2, 3, 4, 5]).unwrap();
+    ///
+    ///    let ids = df
+    ///        .rows_iter_range::<MyRow>(1..3)
+    ///        .unwrap()
+    ///        .map(|row| row.map(|row| row.id))
+    ///        .collect::<PolarsResult<Vec<i32>>>()
+    ///        .unwrap();
+    ///
+    ///    assert_eq!(ids, [2, 3]);
+    ///```
+    fn rows_iter_range<T>(&'a self, range: impl RangeBounds<usize>) -> PolarsResult<T::Iter>
+    where
+        T: FromDataFrameRow<'a>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => end.saturating_add(1),
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => self.height(),
+        };
 
-        assert_eq!("column_1", *columns.get("x1").unwrap());
-        assert_eq!("column_2", *columns.get("x2").unwrap());
+        let options = RowsIterOptions {
+            row_range: Some((start, end)),
+            ..Default::default()
+        };
+        T::from_dataframe_with_options(self, HashMap::new(), options)
     }
 
-    #[test]
-    fn rows_iter_with_columns_should_return_error_when_given_column_not_available() {
-        let df = df!(
-            "x1" => [1i32, 2, 3],
-            "x2" => [1i32, 2, 3]
-        )
-        .unwrap();
-
-        let result = df.rows_iter_with_columns::<TestStruct>(|b| b.x1("y1"));
+    /// Creates a row iterator, which handles None/null values in columns of mandatory (non-`Option`) fields according to
+    /// the given null policy. Fields with an own policy defined by `#[column(on_null = "...")]` keep their policy.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///#[derive(PartialEq)] // for assert_eq
+    ///struct MyRow {
+    ///    id: i32,
+    ///    #[column(on_null = "default")]
+    ///    value: f64,
+    ///}
+    ///
+    ///    let df = df!(
+    ///        "id" => [Some(1i32), None, Some(3)],
+    ///        "value" => [Some(1.0f64), Some(2.0), None]
+    ///    ).unwrap();
+    ///
+    ///    let rows = df
+    ///        .rows_iter_with_null_policy::<MyRow>(NullPolicy::SkipRow)
+    ///        .unwrap()
+    ///        .collect::<PolarsResult<Vec<MyRow>>>()
+    ///        .unwrap();
+    ///
+    ///    assert_eq!(rows, [MyRow { id: 1, value: 1.0 }, MyRow { id: 3, value: 0.0 }]);
+    ///```
+    fn rows_iter_with_null_policy<T>(&'a self, null_policy: NullPolicy) -> PolarsResult<T::Iter>
+    where
+        T: FromDataFrameRow<'a>,
+    {
+        T::from_dataframe_with_null_policy(self, HashMap::new(), null_policy)
+    }
 
-        assert!(result.is_err());
+    /// Creates a row iterator, which casts columns with a dtype not supported by a field to the preferred dtype of the
+    /// field type, e.g. an `Int64` column for an `i32` field. Columns are only cast to numeric and boolean dtypes with
+    /// the strict cast rules of polars, a failing cast like an overflow is reported with the column name. The same
+    /// behavior is enabled for `rows_iter()` by the `#[rows(cast)]` attribute on the row struct.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///#[derive(PartialEq)] // for assert_eq
+    ///struct MyRow {
+    ///    id: i32,
+    ///    value: f64,
+    ///}
+    ///
+    ///    let df = df!(
+    ///        "id" => [1i64, 2],
+    ///        "value" => [1.5f32, 2.5]
+    ///    ).unwrap();
+    ///
+    ///    let rows = df
+    ///        .rows_iter_cast::<MyRow>()
+    ///        .unwrap()
+    ///        .collect::<PolarsResult<Vec<MyRow>>>()
+    ///        .unwrap();
+    ///
+    ///    assert_eq!(rows, [MyRow { id: 1, value: 1.5 }, MyRow { id: 2, value: 2.5 }]);
+    ///```
+    fn rows_iter_cast<T>(&'a self) -> PolarsResult<T::Iter>
+    where
+        T: FromDataFrameRow<'a>,
+    {
+        let options = RowsIterOptions {
+            cast: true,
+            ..Default::default()
+        };
+        T::from_dataframe_with_options(self, HashMap::new(), options)
     }
 
-    #[test]
-    fn rows_iter_with_columns_should_return_valid_iter() {
-        let df = df!(
-            "x_1" => [1i32, 2, 3],
-            "x_2" => [1i32, 2, 3]
-        )
-        .unwrap();
+    /// Iterates over all rows with the given null policy and collects the successfully created rows and the errors of
+    /// the failed rows, instead of stopping at the first failed row. Errors of the iterator creation are returned as
+    /// `Err` instead, including a column of a mandatory field with the `NullPolicy::Error` null policy, which has only
+    /// None/null values and would fail every row.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///struct MyRow {
+    ///    id: i32,
+    ///    value: f64,
+    ///}
+    ///
+    ///    let df = df!(
+    ///        "id" => [1i32, 2, 3],
+    ///        "value" => [Some(1.0f64), None, Some(3.0)]
+    ///    ).unwrap();
+    ///
+    ///    let (rows, errors) = df.rows_collect_with_errors::<MyRow>(NullPolicy::Error).unwrap();
+    ///
+    ///    assert_eq!(rows.iter().map(|row| row.id).collect::<Vec<_>>(), [1, 3]);
+    ///    assert_eq!(errors.len(), 1);
+    ///    assert_eq!(errors[0].row_index, 1);
+    ///
+    ///    let df = df!(
+    ///        "id" => [1i32, 2],
+    ///        "value" => [None::<f64>, None]
+    ///    ).unwrap();
+    ///
+    ///    assert!(df.rows_collect_with_errors::<MyRow>(NullPolicy::Error).is_err());
+    ///```
+    fn rows_collect_with_errors<T>(&'a self, null_policy: NullPolicy) -> PolarsResult<(Vec<T>, Vec<RowError>)>
+    where
+        T: FromDataFrameRow<'a>,
+    {
+        let mut iter = self.rows_iter_with_null_policy::<T>(null_policy)?;
 
-        let result = df.rows_iter_with_columns::<TestStruct>(|b| b.x1("x_1").x2("x_2"));
+        let mut rows = Vec::with_capacity(iter.len());
+        let mut errors = vec![];
 
-        assert!(result.is_ok());
+        while let Some(row_index) = iter.next_row_index() {
+            match iter.try_next() {
+                Some(Ok(row)) => rows.push(row),
+                Some(Err(error)) => errors.push(RowError { row_index, error }),
+                None => break,
+            }
+        }
+
+        Ok((rows, errors))
+    }
+
+    /// Creates an iterator over batches of rows of this DataFrame, e.g. for bulk inserts. Every batch covers `batch_size`
+    /// rows, except for the last one, and is returned as the vector of its rows which did not fail together with the
+    /// errors of the failed rows, like `rows_collect_with_errors()`. `RowsBatches::next_batch_into()` reuses the
+    /// allocations of both vectors across batches.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///struct MyRow<'a> {
+    ///    id: i32,
+    ///    name: &'a str,
+    ///}
+    ///
+    ///    let df = df!(
+    ///        "id" => [1i32, 2, 3],
+    ///        "name" => ["a", "b", "c"]
+    ///    ).unwrap();
+    ///
+    ///    let mut batches = df.rows_batches::<MyRow>(2).unwrap();
+    ///    let mut batch = Vec::new();
+    ///    let mut errors = Vec::new();
+    ///    let mut batch_lengths = Vec::new();
+    ///    while batches.next_batch_into(&mut batch, &mut errors) {
+    ///        assert!(errors.is_empty());
+    ///        batch_lengths.push(batch.len());
+    ///    }
+    ///
+    ///    assert_eq!(batch_lengths, [2, 1]);
+    ///```
+    fn rows_batches<T>(&'a self, batch_size: usize) -> PolarsResult<RowsBatches<'a, T>>
+    where
+        T: FromDataFrameRow<'a>,
+    {
+        RowsBatches::new(self.rows_iter::<T>()?, batch_size)
+    }
+
+    /// Calls the function for every row of this DataFrame, reusing a single row instance whose fields are overwritten
+    /// in place. Owned fields like `String` keep their allocation, e.g. for rows of `Categorical` columns which are
+    /// needed as `String`. Stops at the first row which cannot be created and returns its error.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///struct MyRow {
+    ///    id: i32,
+    ///    name: String,
+    ///}
+    ///
+    ///    let df = df!(
+    ///        "id" => [1i32, 2],
+    ///        "name" => ["a", "b"]
+    ///    ).unwrap();
+    ///
+    ///    let mut names = String::new();
+    ///    df.for_each_row_mut(|row: &mut MyRow| names.push_str(&row.name)).unwrap();
+    ///
+    ///    assert_eq!(names, "ab");
+    ///```
+    fn for_each_row_mut<T>(&'a self, mut f: impl FnMut(&mut T)) -> PolarsResult<()>
+    where
+        T: FromDataFrameRow<'a>,
+    {
+        let mut iter = self.rows_iter::<T>()?;
+        let Some(first_row) = iter.next() else {
+            return Ok(());
+        };
+
+        let mut row = first_row?;
+        f(&mut row);
+        while let Some(result) = iter.try_next_into(&mut row) {
+            result?;
+            f(&mut row);
+        }
+
+        Ok(())
+    }
+
+    /// Creates an iterator over the groups of the given key columns, yielding the key of every group read into `K`
+    /// together with a row iterator over the rows of the group. The rows are selected by the group indices of polars,
+    /// without creating a DataFrame per group like `DataFrame::partition_by()`. The groups are returned in the order
+    /// of their first row.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///struct MyKey<'a> {
+    ///    category: &'a str,
+    ///}
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///struct MyRow {
+    ///    value: i32,
+    ///}
+    ///
+    ///    let df = df!(
+    ///        "category" => ["a", "b", "a"],
+    ///        "value" => [1i32, 2, 3]
+    ///    ).unwrap();
+    ///
+    ///    let sums = df
+    ///        .group_rows_iter::<MyKey, MyRow>(["category"])
+    ///        .unwrap()
+    ///        .map(|group| {
+    ///            let (key, rows) = group?;
+    ///            let sum = rows.map(|row| row.map(|row| row.value)).sum::<PolarsResult<i32>>()?;
+    ///            Ok(format!("{}: {sum}", key.category))
+    ///        })
+    ///        .collect::<PolarsResult<Vec<String>>>()
+    ///        .unwrap();
+    ///
+    ///    assert_eq!(sums, ["a: 4", "b: 2"]);
+    ///```
+    fn group_rows_iter<K, T>(
+        &'a self,
+        by: impl IntoIterator<Item = impl Into<PlSmallStr>>,
+    ) -> PolarsResult<GroupRowsIter<'a, K, T>>
+    where
+        K: FromDataFrameRow<'a>,
+        T: FromDataFrameRow<'a>,
+    {
+        let groups = self.group_by_stable(by)?.take_groups();
+
+        Ok(GroupRowsIter::new(
+            self.row_accessor::<K>()?,
+            self.rows_iter::<T>()?,
+            groups,
+        ))
+    }
+
+    /// Creates a row iterator taking ownership of this DataFrame, so that the iterator is not bound to a borrow of the
+    /// DataFrame and can be returned from functions or moved to other threads. Only row structs without borrowed fields
+    /// are supported, i.e. with `String` instead of `&str` fields.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(FromDataFrameRow)]
+    ///struct MyRow {
+    ///    id: i32,
+    ///    name: String,
+    ///}
+    ///
+    ///fn load_rows() -> PolarsResult<OwnedRowsIter<MyRow>> {
+    ///    let df = df!(
+    ///        "id" => [1i32, 2],
+    ///        "name" => ["a", "b"]
+    ///    )?;
+    ///
+    ///    df.into_rows_iter::<MyRow>()
+    ///}
+    ///
+    ///    let names = load_rows()
+    ///        .unwrap()
+    ///        .map(|row| row.map(|row| row.name))
+    ///        .collect::<PolarsResult<Vec<String>>>()
+    ///        .unwrap();
+    ///
+    ///    assert_eq!(names, ["a", "b"]);
+    ///```
+    fn into_rows_iter<T>(self) -> PolarsResult<OwnedRowsIter<T>>
+    where
+        T: for<'b> FromDataFrameRow<'b> + 'static,
+    {
+        OwnedRowsIter::new(self, RowsIterOptions::default())
+    }
+
+    /// Creates an iterator over untyped row views of this DataFrame. Values are read on demand by column name or
+    /// position, either converted like row struct fields or as polars AnyValue.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///    let df = df!(
+    ///        "id" => [1i32, 2],
+    ///        "name" => ["a", "b"],
+    ///        "value" => [Some(1.0f64), None]
+    ///    ).unwrap();
+    ///
+    ///    for row in df.dyn_rows_iter() {
+    ///        let id = row.get::<i32>("id").unwrap();
+    ///        let name = row.get_by_index::<&str>(1).unwrap();
+    ///        let value = row.get::<Option<f64>>("value").unwrap();
+    ///        println!("{id} {name} {value:?}");
+    ///
+    ///        for (column_name, value) in row.iter() {
+    ///            println!("{column_name}: {value}");
+    ///        }
+    ///    }
+    ///```
+    fn dyn_rows_iter(&'a self) -> DynRowsIter<'a> {
+        DynRowsIter::new(self)
+    }
+
+    /// Creates an accessor for random access to rows of this DataFrame with static column names defined in row
+    /// struct. Column lookups and dtype checks are done once, so the accessor should be reused for many lookups.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///#[derive(PartialEq)] // for assert_eq
+    ///struct MyRow<'a> {
+    ///    id: i32,
+    ///    name: &'a str,
+    ///}
+    ///
+    ///    let df = df!(
+    ///        "id" => [3i32, 1, 2],
+    ///        "name" => ["c", "a", "b"]
+    ///    ).unwrap();
+    ///
+    ///    let accessor = df.row_accessor::<MyRow>().unwrap();
+    ///
+    ///    let sorted_idx = df.column("id").unwrap().as_materialized_series().arg_sort(SortOptions::default());
+    ///    let rows = sorted_idx
+    ///        .into_no_null_iter()
+    ///        .map(|idx| accessor.get(idx as usize))
+    ///        .collect::<PolarsResult<Vec<MyRow>>>()
+    ///        .unwrap();
+    ///
+    ///    assert_eq!(
+    ///        rows,
+    ///        [
+    ///            MyRow { id: 1, name: "a" },
+    ///            MyRow { id: 2, name: "b" },
+    ///            MyRow { id: 3, name: "c" },
+    ///        ]
+    ///    );
+    ///```
+    fn row_accessor<T>(&'a self) -> PolarsResult<RowAccessor<'a, T>>
+    where
+        T: FromDataFrameRow<'a>,
+    {
+        T::create_accessor(self, HashMap::new())
+    }
+
+    /// Creates an accessor for random access to rows of this DataFrame with custom column names, see
+    /// `rows_iter_with_columns()` for defining custom column names.
+    fn row_accessor_with_columns<T>(
+        &'a self,
+        build_fn: impl FnOnce(&mut T::Builder) -> &mut T::Builder,
+    ) -> PolarsResult<RowAccessor<'a, T>>
+    where
+        T: FromDataFrameRow<'a>,
+    {
+        let mut builder = T::create_builder();
+
+        build_fn(&mut builder);
+
+        let columns = builder.build();
+
+        T::create_accessor(self, columns)
+    }
+
+    /// Reads a single row at the given index. For many lookups create a reusable accessor with `row_accessor()`.
+    fn row_at<T>(&'a self, index: usize) -> PolarsResult<T>
+    where
+        T: FromDataFrameRow<'a>,
+    {
+        self.row_accessor::<T>()?.get(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(dead_code)]
+
+    use polars::df;
+    use polars::prelude::{BooleanChunked, DataType, Field, NamedFrom, PolarsResult, Schema};
+
+    use crate::*;
+
+    #[derive(FromDataFrameRow)]
+    struct TestStruct {
+        x1: i32,
+        x2: i32,
+    }
+
+    #[test]
+    fn rows_iter_should_return_error_when_given_column_not_available() {
+        let df = df!(
+            "y1" => [1i32, 2, 3],
+            "x2" => [1i32, 2, 3]
+        )
+        .unwrap();
+
+        let result = df.rows_iter::<TestStruct>();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn builder_should_build_hashmap_with_correct_entries() {
+        let mut builder = TestStruct::create_builder();
+        builder.x1("column_1").x2("column_2");
+        let columns = builder.build();
+
+        assert_eq!("column_1", *columns.get("x1").unwrap());
+        assert_eq!("column_2", *columns.get("x2").unwrap());
+    }
+
+    #[test]
+    fn rows_iter_with_columns_should_return_error_when_given_column_not_available() {
+        let df = df!(
+            "x1" => [1i32, 2, 3],
+            "x2" => [1i32, 2, 3]
+        )
+        .unwrap();
+
+        let result = df.rows_iter_with_columns::<TestStruct>(|b| b.x1("y1"));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rows_iter_with_columns_should_return_valid_iter() {
+        let df = df!(
+            "x_1" => [1i32, 2, 3],
+            "x_2" => [1i32, 2, 3]
+        )
+        .unwrap();
+
+        let result = df.rows_iter_with_columns::<TestStruct>(|b| b.x1("x_1").x2("x_2"));
+
+        assert!(result.is_ok());
+    }
+
+    #[derive(Debug, PartialEq, FromDataFrameRow)]
+    struct TestRow<'a> {
+        id: i32,
+        name: &'a str,
+        value: Option<f64>,
+    }
+
+    fn create_chunked_dataframe() -> polars::prelude::DataFrame {
+        let mut df = df!(
+            "id" => [0i32, 1, 2],
+            "name" => ["a", "b", "c"],
+            "value" => [Some(0.0f64), None, Some(2.0)]
+        )
+        .unwrap();
+        df.vstack_mut(
+            &df!(
+                "id" => [3i32, 4],
+                "name" => ["d", "e"],
+                "value" => [None, Some(4.0f64)]
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        df
+    }
+
+    fn ids<'a>(iter: impl Iterator<Item = polars::prelude::PolarsResult<TestRow<'a>>>) -> Vec<i32> {
+        iter.map(|row| row.unwrap().id).collect()
+    }
+
+    #[test]
+    fn rows_iter_should_report_exact_len() {
+        let df = create_chunked_dataframe();
+        let mut iter = df.rows_iter::<TestRow>().unwrap();
+
+        assert_eq!(5, iter.len());
+        iter.next();
+        iter.next_back();
+        assert_eq!(3, iter.len());
+    }
+
+    #[test]
+    fn rows_iter_should_iterate_in_reverse_across_chunks() {
+        let df = create_chunked_dataframe();
+
+        assert_eq!(vec![4, 3, 2, 1, 0], ids(df.rows_iter::<TestRow>().unwrap().rev()));
+    }
+
+    #[test]
+    fn rows_iter_should_seek_with_nth_and_skip_across_chunks() {
+        let df = create_chunked_dataframe();
+
+        let mut iter = df.rows_iter::<TestRow>().unwrap();
+        assert_eq!(
+            TestRow {
+                id: 3,
+                name: "d",
+                value: None
+            },
+            iter.nth(3).unwrap().unwrap()
+        );
+        assert_eq!(4, iter.next().unwrap().unwrap().id);
+        assert!(iter.next().is_none());
+
+        assert_eq!(vec![2, 3], ids(df.rows_iter::<TestRow>().unwrap().skip(2).take(2)));
+        assert!(df.rows_iter::<TestRow>().unwrap().nth(5).is_none());
+    }
+
+    #[test]
+    fn rows_iter_should_meet_in_the_middle_when_iterating_from_both_ends() {
+        let df = create_chunked_dataframe();
+        let mut iter = df.rows_iter::<TestRow>().unwrap();
+
+        assert_eq!(1, iter.nth(1).unwrap().unwrap().id);
+        assert_eq!(3, iter.nth_back(1).unwrap().unwrap().id);
+        assert_eq!(2, iter.next_back().unwrap().unwrap().id);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+    }
+
+    #[test]
+    fn rows_iter_should_fail_on_creation_when_mandatory_column_contains_only_nulls() {
+        let df = df!(
+            "id" => [None::<i32>, None],
+            "name" => ["a", "b"],
+            "value" => [None::<f64>, None]
+        )
+        .unwrap();
+
+        assert!(df.rows_iter::<TestRow>().is_err());
+
+        let df = df!(
+            "id" => [Some(1i32), None],
+            "name" => ["a", "b"],
+            "value" => [None::<f64>, None]
+        )
+        .unwrap();
+        let mut iter = df.rows_iter::<TestRow>().unwrap();
+
+        assert!(iter.next().unwrap().is_ok());
+        assert!(iter.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn rows_iter_should_fail_on_creation_only_when_all_selected_rows_are_null() {
+        let df = df!(
+            "id" => [Some(0i32), None, None, Some(3)],
+            "name" => ["a", "b", "c", "d"],
+            "value" => [None::<f64>, None, None, None]
+        )
+        .unwrap();
+
+        assert_eq!(2, df.rows_iter_range::<TestRow>(2..4).unwrap().len());
+        assert_eq!(0, df.rows_iter_range::<TestRow>(2..2).unwrap().len());
+
+        let options = RowsIterOptions {
+            row_range: Some((1, 3)),
+            row_offset: 10,
+            ..Default::default()
+        };
+        let error = TestRow::from_dataframe_with_options(&df, Default::default(), options)
+            .err()
+            .unwrap();
+
+        assert!(error.to_string().contains("column id with mandatory values at row 11"));
+    }
+
+    mod rows {
+        use crate::*;
+
+        #[derive(FromDataFrameRow)]
+        pub struct PublicRow {
+            pub id: i32,
+        }
+    }
+
+    struct RowsHolder<'a> {
+        rows: rows::PublicRowRowsIterator<'a>,
+    }
+
+    #[test]
+    fn rows_iter_should_return_nameable_iterator_type() {
+        let df = create_chunked_dataframe();
+
+        let mut holder = RowsHolder {
+            rows: df.rows_iter::<rows::PublicRow>().unwrap(),
+        };
+
+        assert_eq!(0, holder.rows.next().unwrap().unwrap().id);
+        assert_eq!(4, holder.rows.len());
+    }
+
+    #[test]
+    fn rows_iter_validated_should_list_all_columns_with_null_values() {
+        let df = df!(
+            "id" => [None::<i32>, None],
+            "name" => [Some("a"), None],
+            "value" => [None::<f64>, None]
+        )
+        .unwrap();
+
+        let error = df.rows_iter_validated::<TestRow>().err().unwrap().to_string();
+
+        assert!(error.contains("'id' (2 null values, first at row 0)"));
+        assert!(error.contains("'name' (1 null values, first at row 1)"));
+        assert!(!error.contains("'value'"));
+    }
+
+    #[test]
+    fn validate_nulls_should_report_every_column_with_null_values() {
+        let df = df!(
+            "id" => [Some(1i32), None, None],
+            "name" => [Some("a"), Some("b"), None],
+            "value" => [None::<f64>, None, None]
+        )
+        .unwrap();
+
+        match df.validate_nulls::<TestRow>() {
+            Err(RowsIterError::UnexpectedNulls { columns }) => assert_eq!(
+                vec![
+                    NullColumn {
+                        column: "id".to_string(),
+                        null_count: 2,
+                        first_row: 1
+                    },
+                    NullColumn {
+                        column: "name".to_string(),
+                        null_count: 1,
+                        first_row: 2
+                    },
+                ],
+                columns
+            ),
+            result => panic!("expected null columns, got {result:?}"),
+        }
+        assert!(df.slice(0, 1).validate_nulls::<TestRow>().is_ok());
+    }
+
+    #[test]
+    fn validate_nulls_should_check_fields_with_error_null_policy() {
+        #[derive(Debug, FromDataFrameRow)]
+        struct Row {
+            #[column(on_null = "error")]
+            id: i32,
+            #[column(on_null = "skip")]
+            value: f64,
+        }
+
+        let df = df!(
+            "id" => [Some(1i32), None],
+            "value" => [None::<f64>, Some(2.0)]
+        )
+        .unwrap();
+
+        assert_eq!(vec!["id"], Row::get_mandatory_column_names(&Default::default()));
+        match df.validate_nulls::<Row>() {
+            Err(RowsIterError::UnexpectedNulls { columns }) => {
+                assert_eq!(
+                    vec!["id"],
+                    columns.iter().map(|column| column.column.as_str()).collect::<Vec<_>>()
+                )
+            }
+            result => panic!("expected null columns, got {result:?}"),
+        }
+        assert!(df.rows_iter_validated::<Row>().is_err());
+    }
+
+    #[test]
+    fn rows_iter_validated_should_return_iter_when_mandatory_columns_have_no_nulls() {
+        let df = df!(
+            "id" => [1i32, 2],
+            "name" => ["a", "b"],
+            "value" => [None::<f64>, None]
+        )
+        .unwrap();
+
+        assert_eq!(2, df.rows_iter_validated::<TestRow>().unwrap().len());
+        assert!(df.rows_iter_validated::<TestStruct>().is_err());
+    }
+
+    #[derive(Debug, PartialEq, FromDataFrameRow)]
+    struct NullPolicyRow<'a> {
+        id: i32,
+        #[column("label", on_null = "default")]
+        name: &'a str,
+        #[column(on_null = "skip")]
+        value: f64,
+    }
+
+    fn create_dataframe_with_nulls() -> polars::prelude::DataFrame {
+        df!(
+            "id" => [Some(0i32), None, Some(2), Some(3), Some(4)],
+            "label" => [Some("a"), Some("b"), None, Some("d"), Some("e")],
+            "value" => [Some(0.0f64), Some(1.0), Some(2.0), None, Some(4.0)]
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn rows_iter_should_apply_field_null_policies() {
+        let df = create_dataframe_with_nulls();
+
+        let mut iter = df.rows_iter::<NullPolicyRow>().unwrap();
+
+        assert_eq!(4, iter.len());
+        assert_eq!(
+            NullPolicyRow {
+                id: 4,
+                name: "e",
+                value: 4.0
+            },
+            iter.next_back().unwrap().unwrap()
+        );
+        assert_eq!(0, iter.next().unwrap().unwrap().id);
+        assert!(iter.next().unwrap().is_err());
+        assert_eq!(
+            NullPolicyRow {
+                id: 2,
+                name: "",
+                value: 2.0
+            },
+            iter.next().unwrap().unwrap()
+        );
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn rows_iter_with_null_policy_should_apply_policy_to_fields_without_own_policy() {
+        let df = create_dataframe_with_nulls();
+
+        let ids = |null_policy| {
+            df.rows_iter_with_null_policy::<NullPolicyRow>(null_policy)
+                .unwrap()
+                .map(|row| row.unwrap().id)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec![0, 2, 4], ids(NullPolicy::SkipRow));
+        assert_eq!(vec![0, 0, 2, 4], ids(NullPolicy::UseDefault));
+    }
+
+    #[test]
+    fn row_accessor_should_apply_field_null_policies_like_rows_iter() {
+        let df = create_dataframe_with_nulls();
+
+        let accessor = df.row_accessor::<NullPolicyRow>().unwrap();
+
+        assert_eq!(
+            NullPolicyRow {
+                id: 2,
+                name: "",
+                value: 2.0
+            },
+            accessor.get(2).unwrap()
+        );
+        assert!(accessor.get(1).is_err());
+        assert!(accessor.get(3).is_err());
+    }
+
+    /// Field type without a `Default` implementation
+    #[derive(Debug, PartialEq)]
+    struct Score(f64);
+
+    impl<'a> IterFromColumn<'a> for Score {
+        type RawInner = f64;
+        type Iter = <f64 as IterFromColumn<'a>>::Iter;
+
+        fn create_iter(column: &'a polars::prelude::Column) -> PolarsResult<Self::Iter> {
+            <f64 as IterFromColumn<'a>>::create_iter(column)
+        }
+
+        fn accepted_dtypes() -> Vec<DataType> {
+            vec![DataType::Float64]
+        }
+
+        fn get_raw(column: &'a polars::prelude::Column, index: usize) -> PolarsResult<Option<f64>> {
+            <f64 as IterFromColumn<'a>>::get_raw(column, index)
+        }
+
+        fn get_value(polars_value: Option<f64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self> {
+            <f64 as IterFromColumn<'a>>::get_value(polars_value, column_name, dtype).map(Score)
+        }
+    }
+
+    #[test]
+    fn rows_iter_should_not_require_default_for_fields_without_default_policy() {
+        #[derive(Debug, FromDataFrameRow)]
+        struct ScoreRow {
+            score: Score,
+        }
+
+        let df = df!("score" => [Some(1.0f64), None]).unwrap();
+
+        let rows = df
+            .rows_iter_with_null_policy::<ScoreRow>(NullPolicy::UseDefault)
+            .unwrap()
+            .collect::<Vec<_>>();
+
+        assert_eq!(Score(1.0), rows[0].as_ref().unwrap().score);
+        assert!(rows[1].is_err());
+    }
+
+    #[test]
+    fn rows_collect_with_errors_should_return_rows_and_errors_with_row_index() {
+        let df = create_dataframe_with_nulls();
+
+        let (rows, errors) = df.rows_collect_with_errors::<NullPolicyRow>(NullPolicy::Error).unwrap();
+
+        assert_eq!(vec![0, 2, 4], rows.iter().map(|row| row.id).collect::<Vec<_>>());
+        assert_eq!(1, errors.len());
+        assert_eq!(1, errors[0].row_index);
+    }
+
+    #[derive(Debug, PartialEq, FromDataFrameRow)]
+    #[rows(cast)]
+    struct CastRow<'a> {
+        id: i32,
+        name: &'a str,
+        value: Option<f64>,
+        flag: bool,
+    }
+
+    #[test]
+    fn rows_iter_should_cast_columns_of_row_struct_with_cast_attribute() {
+        let df = df!(
+            "id" => ["1", "2"],
+            "name" => ["a", "b"],
+            "value" => [Some(1i32), None],
+            "flag" => [1u8, 0]
+        )
+        .unwrap();
+
+        let rows = df
+            .rows_iter::<CastRow>()
+            .unwrap()
+            .map(|row| row.unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                CastRow {
+                    id: 1,
+                    name: "a",
+                    value: Some(1.0),
+                    flag: true
+                },
+                CastRow {
+                    id: 2,
+                    name: "b",
+                    value: None,
+                    flag: false
+                }
+            ],
+            rows
+        );
+        assert_eq!(
+            CastRow {
+                id: 2,
+                name: "b",
+                value: None,
+                flag: false
+            },
+            df.row_at::<CastRow>(1).unwrap()
+        );
+    }
+
+    #[test]
+    fn rows_iter_cast_should_report_lossy_casts_and_keep_rejecting_incompatible_dtypes() {
+        let df = df!(
+            "id" => [1i64, i64::MAX],
+            "name" => [1i32, 2],
+            "value" => [Some(1.0f64), None],
+            "flag" => [true, false]
+        )
+        .unwrap();
+
+        #[derive(Debug, FromDataFrameRow)]
+        struct Row<'a> {
+            id: i32,
+            name: &'a str,
+        }
+
+        let error = df.rows_iter_cast::<Row>().err().unwrap().to_string();
+
+        assert!(error.contains("Cannot cast column 'id' of field 'id' to 'i32'"));
+        assert!(error.contains("Column 'name' of field 'name' has dtype 'i32', expected 'str'"));
+        assert!(df.rows_iter::<Row>().is_err());
+    }
+
+    #[test]
+    fn rows_iter_cast_should_iterate_cast_values_from_both_ends() {
+        #[derive(Debug, FromDataFrameRow)]
+        struct Row {
+            id: i32,
+        }
+
+        let df = df!("id" => [1i64, 2, 3, 4]).unwrap();
+
+        let mut iter = df.rows_iter_cast::<Row>().unwrap();
+        assert_eq!(2, iter.nth(1).unwrap().unwrap().id);
+        assert_eq!(4, iter.next_back().unwrap().unwrap().id);
+        assert_eq!(1, iter.len());
+        assert_eq!(vec![3], iter.map(|row| row.unwrap().id).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn rows_iter_cast_should_not_cast_columns_of_types_without_cast_values() {
+        #[derive(Debug, FromDataFrameRow)]
+        struct ScoreRow {
+            score: Score,
+        }
+
+        let df = df!("score" => [1.0f32]).unwrap();
+
+        let error = df.rows_iter_cast::<ScoreRow>().err().unwrap().to_string();
+
+        assert!(error.contains("Column 'score' of field 'score' has dtype 'f32', expected 'f64'"));
+    }
+
+    #[test]
+    fn validate_schema_should_accept_castable_dtypes_with_cast_attribute() {
+        let schema = Schema::from_iter([
+            Field::new("id".into(), DataType::Int64),
+            Field::new("name".into(), DataType::String),
+            Field::new("value".into(), DataType::Float32),
+            Field::new("flag".into(), DataType::Boolean),
+        ]);
+
+        assert!(CastRow::validate_schema(&schema).is_ok());
+        assert!(TestRow::validate_schema(&schema).is_err());
+    }
+
+    #[test]
+    fn rows_iter_should_be_movable_to_another_thread() {
+        #[derive(Debug, PartialEq, FromDataFrameRow)]
+        struct Row<'a> {
+            id: i32,
+            name: &'a str,
+        }
+
+        let df = df!(
+            "id" => [1i32, 2, 3],
+            "name" => ["a", "b", "c"]
+        )
+        .unwrap();
+
+        let iter = df.rows_iter::<Row>().unwrap();
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        let names = std::thread::scope(|scope| {
+            scope.spawn(move || {
+                for row in iter {
+                    sender.send(row.unwrap()).unwrap();
+                }
+            });
+            receiver.iter().map(|row| row.name).collect::<Vec<_>>()
+        });
+
+        assert_eq!(["a", "b", "c"], names.as_slice());
+    }
+
+    #[test]
+    fn for_each_row_mut_should_reuse_the_allocations_of_owned_fields() {
+        #[derive(Debug, FromDataFrameRow)]
+        struct Row {
+            id: i32,
+            name: String,
+            label: Option<String>,
+        }
+
+        let df = df!(
+            "id" => [1i32, 2, 3],
+            "name" => ["first name", "b", "c"],
+            "label" => [Some("x"), None, Some("z")]
+        )
+        .unwrap();
+
+        let mut values = Vec::new();
+        let mut name_buffers = Vec::new();
+        df.for_each_row_mut(|row: &mut Row| {
+            values.push((row.id, row.name.clone(), row.label.clone()));
+            name_buffers.push(row.name.as_ptr());
+        })
+        .unwrap();
+
+        assert_eq!(
+            vec![
+                (1, "first name".to_string(), Some("x".to_string())),
+                (2, "b".to_string(), None),
+                (3, "c".to_string(), Some("z".to_string()))
+            ],
+            values
+        );
+        assert!(name_buffers.iter().all(|buffer| *buffer == name_buffers[0]));
+    }
+
+    #[test]
+    fn for_each_row_mut_should_stop_at_the_first_failing_row() {
+        #[derive(Debug, FromDataFrameRow)]
+        struct Row {
+            name: String,
+        }
+
+        let df = df!("name" => [Some("a"), None, Some("c")]).unwrap();
+
+        let mut names = Vec::new();
+        let result = df.for_each_row_mut(|row: &mut Row| names.push(row.name.clone()));
+
+        assert!(result.is_err());
+        assert_eq!(vec!["a".to_string()], names);
+    }
+
+    #[test]
+    fn rows_iter_filtered_should_skip_rows_outside_the_mask_without_converting_them() {
+        #[derive(Debug, FromDataFrameRow)]
+        struct Row {
+            #[row_index]
+            index: usize,
+            value: f64,
+        }
+
+        let df = df!("value" => [Some(1.0f64), None, Some(3.0), Some(4.0)]).unwrap();
+        let mask = BooleanChunked::new("mask".into(), [Some(true), Some(false), None, Some(true)]);
+
+        let rows = df
+            .rows_iter_filtered::<Row>(&mask)
+            .unwrap()
+            .map(|row| row.map(|row| (row.index, row.value)))
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(vec![(0, 1.0), (3, 4.0)], rows);
+        assert!(df
+            .rows_iter_filtered::<Row>(&BooleanChunked::new("mask".into(), [true]))
+            .is_err());
+    }
+
+    #[test]
+    fn filter_attribute_should_combine_the_filter_column_with_the_mask() {
+        #[derive(Debug, FromDataFrameRow)]
+        #[rows(filter = "is_active")]
+        struct Row {
+            id: i32,
+        }
+
+        let df = df!(
+            "id" => [1i32, 2, 3, 4],
+            "is_active" => [true, false, true, true]
+        )
+        .unwrap();
+        let mask = BooleanChunked::new("mask".into(), [true, true, true, false]);
+
+        let ids = |iter: PolarsResult<RowRowsIterator>| iter.unwrap().map(|row| row.unwrap().id).collect::<Vec<_>>();
+
+        assert_eq!(vec![1, 3, 4], ids(df.rows_iter::<Row>()));
+        assert_eq!(vec![1, 3], ids(df.rows_iter_filtered::<Row>(&mask)));
+        assert_eq!(Some("is_active"), Row::filter_column());
+        assert!(df.drop("is_active").unwrap().rows_iter::<Row>().is_err());
+    }
+
+    #[test]
+    fn rows_iter_range_should_iterate_rows_of_the_range_across_chunks() {
+        #[derive(Debug, FromDataFrameRow)]
+        struct Row {
+            #[row_index]
+            index: usize,
+            id: i32,
+            #[column(on_null = "skip")]
+            value: f64,
+        }
+
+        let mut df = df!(
+            "id" => [0i32, 1, 2],
+            "value" => [Some(0.0f64), None, Some(2.0)]
+        )
+        .unwrap();
+        df.vstack_mut(&df!("id" => [3i32, 4, 5], "value" => [Some(3.0f64), Some(4.0), None]).unwrap())
+            .unwrap();
+
+        let ids = |iter: PolarsResult<RowRowsIterator>| {
+            iter.unwrap()
+                .map(|row| row.map(|row| (row.index, row.id)).unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec![(2, 2), (3, 3)], ids(df.rows_iter_range::<Row>(1..4)));
+        assert_eq!(vec![(3, 3), (4, 4)], ids(df.rows_iter_range::<Row>(3..)));
+        assert_eq!(vec![(0, 0), (2, 2)], ids(df.rows_iter_range::<Row>(..=2)));
+        assert_eq!(Vec::<(usize, i32)>::new(), ids(df.rows_iter_range::<Row>(6..6)));
+        assert!(df.rows_iter_range::<Row>(2..7).is_err());
     }
 }
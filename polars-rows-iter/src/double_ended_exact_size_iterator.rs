@@ -0,0 +1,5 @@
+/// Iterator with a known length that can be consumed from both ends, implemented by all column and row iterators.
+/// Column iterators implement `nth()` and `nth_back()` by seeking, which makes skipping rows cheap.
+pub trait DoubleEndedExactSizeIterator: DoubleEndedIterator + ExactSizeIterator {}
+
+impl<I> DoubleEndedExactSizeIterator for I where I: DoubleEndedIterator + ExactSizeIterator {}
@@ -0,0 +1,210 @@
+use polars::prelude::*;
+
+use crate::IterFromColumn;
+
+/// Untyped view on a single dataframe row, values are read on demand from the underlying columns
+#[derive(Clone, Copy)]
+pub struct Row<'a> {
+    dataframe: &'a DataFrame,
+    index: usize,
+}
+
+impl<'a> Row<'a> {
+    /// Position of this row within the dataframe
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Number of values (columns) in this row
+    pub fn len(&self) -> usize {
+        self.dataframe.width()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reads the value of the given column, converted in the same way as row struct fields of type `T`
+    pub fn get<T>(&self, column_name: &str) -> PolarsResult<T>
+    where
+        T: IterFromColumn<'a>,
+    {
+        self.get_from_column(self.dataframe.column(column_name)?)
+    }
+
+    /// Reads the value of the column at the given position, converted in the same way as row struct fields of type `T`
+    pub fn get_by_index<T>(&self, column_index: usize) -> PolarsResult<T>
+    where
+        T: IterFromColumn<'a>,
+    {
+        self.get_from_column(self.column_by_index(column_index)?)
+    }
+
+    /// Reads the value of the given column as polars AnyValue
+    pub fn get_any(&self, column_name: &str) -> PolarsResult<AnyValue<'a>> {
+        self.dataframe.column(column_name)?.get(self.index)
+    }
+
+    /// Reads the value of the column at the given position as polars AnyValue
+    pub fn get_any_by_index(&self, column_index: usize) -> PolarsResult<AnyValue<'a>> {
+        self.column_by_index(column_index)?.get(self.index)
+    }
+
+    /// Iterates over all values of this row as pairs of column name and AnyValue
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, AnyValue<'a>)> + 'a {
+        let index = self.index;
+        self.dataframe.get_columns().iter().map(move |column| {
+            let value = column.get(index).expect("row index is within the dataframe height");
+            (column.name().as_str(), value)
+        })
+    }
+
+    fn column_by_index(&self, column_index: usize) -> PolarsResult<&'a Column> {
+        self.dataframe.get_columns().get(column_index).ok_or_else(
+            || polars_err!(OutOfBounds: "Column index {column_index} is out of bounds for dataframe width {}", self.len()),
+        )
+    }
+
+    fn get_from_column<T>(&self, column: &'a Column) -> PolarsResult<T>
+    where
+        T: IterFromColumn<'a>,
+    {
+        let value = T::get_raw(column, self.index)?;
+        Ok(T::get_row_value(
+            value,
+            column.name().as_str(),
+            column.dtype(),
+            self.index,
+        )?)
+    }
+}
+
+/// Iterator over untyped [`Row`] views of a dataframe
+pub struct DynRowsIter<'a> {
+    dataframe: &'a DataFrame,
+    front: usize,
+    back: usize,
+}
+
+impl<'a> DynRowsIter<'a> {
+    pub fn new(dataframe: &'a DataFrame) -> Self {
+        Self {
+            dataframe,
+            front: 0,
+            back: dataframe.height(),
+        }
+    }
+}
+
+impl<'a> Iterator for DynRowsIter<'a> {
+    type Item = Row<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        if self.front == self.back {
+            return None;
+        }
+
+        let index = self.front;
+        self.front += 1;
+
+        Some(Row {
+            dataframe: self.dataframe,
+            index,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for DynRowsIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some(Row {
+            dataframe: self.dataframe,
+            index: self.back,
+        })
+    }
+}
+
+impl ExactSizeIterator for DynRowsIter<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use polars::prelude::*;
+
+    use crate::*;
+
+    fn create_dataframe() -> DataFrame {
+        df!(
+            "id" => [1i32, 2, 3],
+            "name" => ["a", "b", "c"],
+            "value" => [Some(1.0f64), None, Some(3.0)]
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn get_should_convert_values_like_row_struct_fields() {
+        let df = create_dataframe();
+        let row = df.dyn_rows_iter().nth(1).unwrap();
+
+        assert_eq!(1, row.index());
+        assert_eq!(2, row.get::<i32>("id").unwrap());
+        assert_eq!("b", row.get::<&str>("name").unwrap());
+        assert_eq!("b".to_string(), row.get_by_index::<String>(1).unwrap());
+        assert_eq!(None, row.get::<Option<f64>>("value").unwrap());
+    }
+
+    #[test]
+    fn get_should_return_error_for_unexpected_null_missing_column_or_wrong_dtype() {
+        let df = create_dataframe();
+        let row = df.dyn_rows_iter().nth(1).unwrap();
+
+        assert!(row.get::<f64>("value").is_err());
+        assert!(row.get::<i32>("missing").is_err());
+        assert!(row.get::<&str>("id").is_err());
+        assert!(row.get_by_index::<i32>(3).is_err());
+    }
+
+    #[test]
+    fn iter_should_return_column_names_and_any_values() {
+        let df = create_dataframe();
+        let row = df.dyn_rows_iter().last().unwrap();
+
+        let values = row.iter().collect_vec();
+
+        assert_eq!(
+            values,
+            [
+                ("id", AnyValue::Int32(3)),
+                ("name", AnyValue::String("c")),
+                ("value", AnyValue::Float64(3.0))
+            ]
+        );
+        assert_eq!(AnyValue::String("c"), row.get_any("name").unwrap());
+        assert_eq!(AnyValue::Int32(3), row.get_any_by_index(0).unwrap());
+    }
+
+    #[test]
+    fn dyn_rows_iter_should_support_len_and_reverse_iteration() {
+        let df = create_dataframe();
+        let iter = df.dyn_rows_iter();
+
+        assert_eq!(3, iter.len());
+        assert_eq!(vec![2, 1, 0], iter.rev().map(|row| row.index()).collect_vec());
+    }
+}
@@ -0,0 +1,80 @@
+use std::mem::discriminant;
+
+use polars::prelude::*;
+
+/// Description of the column read into a field of a row struct, returned by `FromDataFrameRow::expected_schema()`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExpectedColumn {
+    /// Name of the struct field, or `FILTER_FIELD` for the filter column of `#[rows(filter = "...")]`
+    pub field: &'static str,
+    /// Name of the column, as defined by the `#[column(...)]` attribute or the field name
+    pub column: &'static str,
+    /// Polars dtypes the column can have. Parametrized dtypes like `Datetime(..)` or `List(..)` are listed once with
+    /// placeholder parameters and stand for the dtype with any parameters.
+    pub dtypes: Vec<DataType>,
+    /// Whether the column can contain None/null values, which is the case for `Option` fields and fields with
+    /// `on_null = "skip"` or `on_null = "default"`
+    pub nullable: bool,
+}
+
+impl ExpectedColumn {
+    /// Checks if a column of the given dtype can be read into the field
+    pub fn accepts(&self, dtype: &DataType) -> bool {
+        dtype_matches_any(&self.dtypes, dtype)
+    }
+}
+
+/// Checks if the dtype is one of the accepted dtypes, ignoring dtype parameters like time units or inner dtypes
+pub(crate) fn dtype_matches_any(accepted_dtypes: &[DataType], dtype: &DataType) -> bool {
+    accepted_dtypes
+        .iter()
+        .any(|accepted| discriminant(accepted) == discriminant(dtype))
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::prelude::*;
+
+    use crate::*;
+
+    #[derive(Debug, FromDataFrameRow)]
+    #[allow(dead_code)]
+    struct TestRow<'a> {
+        id: i64,
+        #[column("label")]
+        name: &'a str,
+        #[column(on_null = "skip")]
+        flag: bool,
+        value: Option<f64>,
+    }
+
+    #[test]
+    fn expected_schema_should_describe_all_fields() {
+        let schema = TestRow::expected_schema();
+
+        assert_eq!(
+            ["id", "label", "flag", "value"],
+            schema.iter().map(|column| column.column).collect::<Vec<_>>().as_slice()
+        );
+        assert_eq!("name", schema[1].field);
+        assert_eq!(
+            [false, false, true, true],
+            schema
+                .iter()
+                .map(|column| column.nullable)
+                .collect::<Vec<_>>()
+                .as_slice()
+        );
+        assert_eq!(vec![DataType::Float64], schema[3].dtypes);
+    }
+
+    #[test]
+    fn expected_column_should_accept_dtypes_with_any_parameters() {
+        let id = &TestRow::expected_schema()[0];
+
+        assert!(id.accepts(&DataType::Int64));
+        assert!(id.accepts(&DataType::Datetime(TimeUnit::Nanoseconds, Some("UTC".into()))));
+        assert!(id.accepts(&DataType::Duration(TimeUnit::Milliseconds)));
+        assert!(!id.accepts(&DataType::Int32));
+    }
+}
@@ -2,18 +2,107 @@ use std::collections::HashMap;
 
 use polars::prelude::*;
 
+use crate::{
+    DoubleEndedExactSizeIterator, ExpectedColumn, NullPolicy, ResolvedColumns, RowAccessor, RowGetter,
+    RowIndexIterator, RowsIterError, RowsIterOptions, SeekRowsIterator, SelectRowsIterator, TryRowsIterator,
+};
+
 pub trait ColumnNameBuilder<'a> {
     fn build(self) -> HashMap<&'a str, &'a str>;
 }
 
 pub trait FromDataFrameRow<'a> {
     type Builder: ColumnNameBuilder<'a>;
-    fn from_dataframe(
+
+    /// Row iterator generated by the derive macro, named `{RowStruct}RowsIterator`, which can be moved to other threads
+    type Iter: DoubleEndedExactSizeIterator<Item = PolarsResult<Self>>
+        + RowIndexIterator
+        + TryRowsIterator<Row = Self>
+        + SelectRowsIterator
+        + SeekRowsIterator
+        + Clone
+        + Send
+        + 'a
+    where
+        Self: Sized;
+
+    /// Row getter of row accessors generated by the derive macro, named `{RowStruct}RowGetter`
+    type Getter: RowGetter<Row = Self> + Send + 'a
+    where
+        Self: Sized;
+
+    fn from_dataframe(dataframe: &'a DataFrame, columns: HashMap<&str, &str>) -> PolarsResult<Self::Iter>
+    where
+        Self: Sized,
+    {
+        Self::from_dataframe_with_null_policy(dataframe, columns, NullPolicy::Error)
+    }
+
+    /// Creates the row iterator, the null policy applies to all mandatory fields without an own `on_null` policy
+    fn from_dataframe_with_null_policy(
+        dataframe: &'a DataFrame,
+        columns: HashMap<&str, &str>,
+        null_policy: NullPolicy,
+    ) -> PolarsResult<Self::Iter>
+    where
+        Self: Sized,
+    {
+        let options = RowsIterOptions {
+            null_policy,
+            ..Default::default()
+        };
+        Self::from_dataframe_with_options(dataframe, columns, options)
+    }
+
+    fn from_dataframe_with_options(
+        dataframe: &'a DataFrame,
+        columns: HashMap<&str, &str>,
+        options: RowsIterOptions,
+    ) -> PolarsResult<Self::Iter>
+    where
+        Self: Sized,
+    {
+        Self::from_dataframe_with_filter(dataframe, columns, options, None)
+    }
+
+    /// Creates the row iterator over the rows where the filter mask is true, in addition to the filter column of
+    /// `#[rows(filter = "...")]`. The values of the other rows are skipped without being converted.
+    fn from_dataframe_with_filter(
         dataframe: &'a DataFrame,
         columns: HashMap<&str, &str>,
-    ) -> PolarsResult<Box<dyn Iterator<Item = PolarsResult<Self>> + 'a>>
+        options: RowsIterOptions,
+        filter: Option<&BooleanChunked>,
+    ) -> PolarsResult<Self::Iter>
+    where
+        Self: Sized;
+
+    fn create_accessor(dataframe: &'a DataFrame, columns: HashMap<&str, &str>) -> PolarsResult<RowAccessor<'a, Self>>
     where
         Self: Sized;
 
     fn create_builder() -> Self::Builder;
+
+    /// Names of the columns read into mandatory (non-`Option`) fields without an own `on_null` policy or with
+    /// `on_null = "error"`, considering the given custom column names
+    fn get_mandatory_column_names<'c>(columns: &HashMap<&str, &'c str>) -> Vec<&'c str>;
+
+    /// Name of the boolean column selecting the rows to iterate, defined by `#[rows(filter = "...")]`
+    fn filter_column() -> Option<&'static str>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Describes the columns read by the row struct with their accepted dtypes and nullability, in field order followed
+    /// by the filter column of `#[rows(filter = "...")]`. Custom column names given by a builder are not considered.
+    fn expected_schema() -> Vec<ExpectedColumn>
+    where
+        Self: Sized;
+
+    /// Performs the same column lookups and dtype checks as the iterator creation against a schema only, e.g. the schema
+    /// of a file or `LazyFrame::collect_schema()` before reading any data. Returns all errors at once on mismatch.
+    fn validate_schema(schema: &Schema) -> Result<ResolvedColumns, RowsIterError>
+    where
+        Self: Sized;
 }
@@ -0,0 +1,191 @@
+use polars::prelude::*;
+
+use crate::{FromDataFrameRow, RowAccessor, SelectRowsIterator};
+
+/// Iterator over the groups of a dataframe, created by `DataframeRowsIterExt::group_rows_iter()`. Yields the key of
+/// every group together with a row iterator over the rows of the group, in the order of the first row of each group.
+pub struct GroupRowsIter<'a, K, T>
+where
+    K: FromDataFrameRow<'a>,
+    T: FromDataFrameRow<'a>,
+{
+    keys: RowAccessor<'a, K>,
+    rows: T::Iter,
+    groups: GroupPositions,
+    next_group: usize,
+}
+
+impl<'a, K, T> GroupRowsIter<'a, K, T>
+where
+    K: FromDataFrameRow<'a>,
+    T: FromDataFrameRow<'a>,
+{
+    /// Creates a group iterator from the group indices of a stable group by, the key accessor and an unmoved row
+    /// iterator of the same dataframe. The row iterators of the groups are narrowed copies of the given row iterator,
+    /// which share its column chunks.
+    pub fn new(keys: RowAccessor<'a, K>, rows: T::Iter, groups: GroupPositions) -> Self {
+        Self {
+            keys,
+            rows,
+            groups,
+            next_group: 0,
+        }
+    }
+}
+
+impl<'a, K, T> Iterator for GroupRowsIter<'a, K, T>
+where
+    K: FromDataFrameRow<'a>,
+    T: FromDataFrameRow<'a>,
+{
+    type Item = PolarsResult<(K, T::Iter)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_group == self.groups.len() {
+            return None;
+        }
+
+        let rows = match self.groups.get(self.next_group) {
+            GroupsIndicator::Idx((_, rows)) => rows.iter().map(|row| *row as usize).collect::<Vec<_>>(),
+            GroupsIndicator::Slice([first, len]) => (first as usize..(first + len) as usize).collect(),
+        };
+        self.next_group += 1;
+
+        // the group indices are not guaranteed to be sorted, the key is read from the first row of the group
+        let key = match rows.iter().min() {
+            Some(first) => self.keys.get(*first),
+            None => Err(polars_err!(ComputeError: "Group without rows")),
+        };
+
+        Some(key.map(|key| (key, self.rows.select_rows(rows))))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.groups.len() - self.next_group;
+        (len, Some(len))
+    }
+}
+
+impl<'a, K, T> ExactSizeIterator for GroupRowsIter<'a, K, T>
+where
+    K: FromDataFrameRow<'a>,
+    T: FromDataFrameRow<'a>,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::prelude::*;
+
+    use crate::*;
+
+    #[derive(Debug, PartialEq, FromDataFrameRow)]
+    struct Key<'a> {
+        category: &'a str,
+        year: i32,
+    }
+
+    #[derive(Debug, PartialEq, FromDataFrameRow)]
+    struct TestRow {
+        #[row_index]
+        index: usize,
+        #[column(on_null = "skip")]
+        value: f64,
+    }
+
+    fn create_dataframe() -> DataFrame {
+        let mut df = df!(
+            "category" => ["a", "b", "a", "b", "c"],
+            "year" => [2024i32, 2024, 2024, 2025, 2024],
+            "value" => [Some(1.0f64), Some(2.0), None, Some(4.0), Some(5.0)]
+        )
+        .unwrap();
+        let second_chunk = df.slice(0, 3);
+        df.vstack_mut(&second_chunk).unwrap();
+        df
+    }
+
+    #[test]
+    fn group_rows_iter_should_yield_groups_in_order_of_their_first_row() {
+        let df = create_dataframe();
+
+        let groups = df.group_rows_iter::<Key, TestRow>(["category", "year"]).unwrap();
+        assert_eq!(4, groups.len());
+
+        let groups = groups
+            .map(|group| {
+                let (key, rows) = group.unwrap();
+                let rows = rows.map(|row| row.unwrap()).collect::<Vec<_>>();
+                (
+                    (key.category, key.year),
+                    rows.iter().map(|row| (row.index, row.value)).collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (("a", 2024), vec![(0, 1.0), (5, 1.0)]),
+                (("b", 2024), vec![(1, 2.0), (6, 2.0)]),
+                (("b", 2025), vec![(3, 4.0)]),
+                (("c", 2024), vec![(4, 5.0)]),
+            ],
+            groups
+        );
+    }
+
+    #[test]
+    fn group_rows_iter_should_iterate_group_rows_from_both_ends() {
+        let df = create_dataframe();
+
+        let (_, mut rows) = df
+            .group_rows_iter::<Key, TestRow>(["category", "year"])
+            .unwrap()
+            .next()
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(2, rows.len());
+        assert_eq!(5, rows.next_back().unwrap().unwrap().index);
+        assert_eq!(0, rows.next().unwrap().unwrap().index);
+        assert!(rows.next().is_none());
+    }
+
+    #[test]
+    fn select_rows_should_read_the_selected_rows_of_a_moved_iterator() {
+        let df = create_dataframe();
+
+        let mut rows = df.rows_iter::<TestRow>().unwrap();
+        assert_eq!(0, rows.next().unwrap().unwrap().index);
+        assert_eq!(6, rows.next_back().unwrap().unwrap().index);
+
+        let selected = rows
+            .select_rows([0, 2, 3, 5, 6])
+            .map(|row| row.unwrap())
+            .map(|row| (row.index, row.value))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![(3, 4.0), (5, 1.0)], selected);
+
+        let selected = rows
+            .select_rows([5, 3, 5])
+            .map(|row| row.unwrap().index)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![3, 5], selected);
+    }
+
+    #[test]
+    fn group_rows_iter_should_fail_on_creation_for_missing_columns() {
+        let df = create_dataframe();
+
+        assert!(df.group_rows_iter::<Key, TestRow>(["category", "missing"]).is_err());
+        assert!(df
+            .clone()
+            .drop("value")
+            .unwrap()
+            .group_rows_iter::<Key, TestRow>(["category", "year"])
+            .is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::RowIndexIterator;
+
+/// Row iterator yielding every row together with its dataframe index, created by
+/// `DataframeRowsIterExt::rows_iter_indexed()`. Unlike `enumerate()`, the index stays correct if rows are skipped.
+pub struct IndexedRowsIter<I> {
+    iter: I,
+}
+
+impl<I> IndexedRowsIter<I>
+where
+    I: RowIndexIterator,
+{
+    pub fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<I> Iterator for IndexedRowsIter<I>
+where
+    I: RowIndexIterator,
+{
+    type Item = (usize, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let row_index = self.iter.next_row_index()?;
+        Some((row_index, self.iter.next()?))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> ExactSizeIterator for IndexedRowsIter<I> where I: RowIndexIterator + ExactSizeIterator {}
+
+#[cfg(test)]
+mod tests {
+    use polars::prelude::*;
+
+    use crate::*;
+
+    #[derive(Debug, PartialEq, FromDataFrameRow)]
+    struct TestRow {
+        #[row_index]
+        index: u32,
+        #[column(on_null = "skip")]
+        value: f64,
+    }
+
+    fn create_dataframe() -> DataFrame {
+        df!("value" => [Some(1.0f64), None, Some(3.0), None, Some(5.0)]).unwrap()
+    }
+
+    #[test]
+    fn rows_iter_indexed_should_return_dataframe_indices_of_skipped_rows() {
+        let df = create_dataframe();
+
+        let indices = df
+            .rows_iter_indexed::<TestRow>()
+            .unwrap()
+            .map(|(index, row)| (index, row.unwrap().index))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![(0, 0), (2, 2), (4, 4)], indices);
+    }
+
+    #[test]
+    fn rows_iter_indexed_with_offset_should_return_indices_of_the_sliced_dataframe() {
+        let df = create_dataframe();
+        let slice = df.slice(1, 4);
+
+        let indices = slice
+            .rows_iter_indexed_with_offset::<TestRow>(1)
+            .unwrap()
+            .map(|(index, row)| (index, row.unwrap()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (2, TestRow { index: 2, value: 3.0 }),
+                (4, TestRow { index: 4, value: 5.0 })
+            ],
+            indices
+        );
+    }
+
+    #[test]
+    fn row_index_field_should_honour_row_offset_and_reverse_iteration() {
+        let df = create_dataframe().slice(2, 3);
+        let options = RowsIterOptions {
+            row_offset: 2,
+            ..Default::default()
+        };
+
+        let indices = TestRow::from_dataframe_with_options(&df, Default::default(), options)
+            .unwrap()
+            .rev()
+            .map(|row| row.unwrap().index)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![4, 2], indices);
+        assert_eq!(2, create_dataframe().row_at::<TestRow>(2).unwrap().index);
+    }
+
+    #[test]
+    fn row_index_field_should_fail_for_indices_out_of_range_of_the_field_type() {
+        #[derive(Debug, FromDataFrameRow)]
+        #[allow(dead_code)]
+        struct SmallIndexRow {
+            #[row_index]
+            index: u8,
+            value: Option<f64>,
+        }
+
+        let df = create_dataframe();
+        let options = RowsIterOptions {
+            row_offset: 255,
+            ..Default::default()
+        };
+
+        let mut iter = SmallIndexRow::from_dataframe_with_options(&df, Default::default(), options).unwrap();
+
+        assert_eq!(255, iter.next().unwrap().unwrap().index);
+        let error = iter.try_next().unwrap().err().unwrap();
+        assert_eq!(Some("index"), error.column());
+        assert_eq!(Some(256), error.row());
+    }
+}
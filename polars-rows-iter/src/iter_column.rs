@@ -0,0 +1,45 @@
+use polars::prelude::*;
+
+/// Column read by a row iterator or accessor, either a column of the dataframe or a column cast to the preferred dtype
+/// of the field type. Gives access to the column name and dtype for error messages.
+#[derive(Clone)]
+pub struct IterColumn<'a> {
+    column: &'a Column,
+    cast_dtype: Option<DataType>,
+}
+
+impl<'a> IterColumn<'a> {
+    pub fn new(column: &'a Column) -> Self {
+        Self {
+            column,
+            cast_dtype: None,
+        }
+    }
+
+    /// Creates the column of a dataframe column, which is read cast to the given dtype
+    pub fn cast(column: &'a Column, dtype: DataType) -> Self {
+        Self {
+            column,
+            cast_dtype: Some(dtype),
+        }
+    }
+
+    /// Column of the dataframe, before any cast. Casts keep the None/null values of the column.
+    #[inline]
+    pub fn column(&self) -> &'a Column {
+        self.column
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        self.column.name().as_str()
+    }
+
+    #[inline]
+    pub fn dtype(&self) -> &DataType {
+        match &self.cast_dtype {
+            Some(dtype) => dtype,
+            None => self.column.dtype(),
+        }
+    }
+}
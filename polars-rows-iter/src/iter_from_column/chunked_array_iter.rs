@@ -0,0 +1,470 @@
+use std::sync::Arc;
+
+use polars::prelude::*;
+
+use crate::ColumnIter;
+
+/// Dtype of the chunked arrays read by `ChunkedArrayIter`, gives access to the contiguous values of numeric chunks
+pub trait ChunkValues: PolarsDataType {
+    /// Values slice of the chunk, or None for dtypes without a contiguous values buffer
+    fn values_slice<'a>(array: &'a Self::Array) -> Option<&'a [Self::Physical<'a>]>;
+
+    /// Reads a value of a chunk owned by the iterator, only called for the dtypes of `OwnedChunkValues`
+    fn owned_value<'a>(array: &Self::Array, index: usize) -> Option<Self::Physical<'a>>;
+}
+
+/// Dtype of chunked arrays whose chunks can be owned by `ChunkedArrayIter`, as their values do not borrow from the
+/// chunk. Columns cast by the row iterators are read with owned chunks.
+pub trait OwnedChunkValues: ChunkValues {}
+
+macro_rules! impl_chunk_values {
+    (slice: $($polars_type:ty),*; owned_array: $($owned_polars_type:ty),*; array: $($array_polars_type:ty),*) => {
+        $(impl ChunkValues for $polars_type {
+            #[inline]
+            fn values_slice<'a>(array: &'a Self::Array) -> Option<&'a [Self::Physical<'a>]> {
+                Some(array.values().as_slice())
+            }
+
+            #[inline]
+            fn owned_value<'a>(array: &Self::Array, index: usize) -> Option<Self::Physical<'a>> {
+                array.get(index)
+            }
+        }
+
+        impl OwnedChunkValues for $polars_type {})*
+        $(impl ChunkValues for $owned_polars_type {
+            #[inline]
+            fn values_slice<'a>(_array: &'a Self::Array) -> Option<&'a [Self::Physical<'a>]> {
+                None
+            }
+
+            #[inline]
+            fn owned_value<'a>(array: &Self::Array, index: usize) -> Option<Self::Physical<'a>> {
+                array.get(index)
+            }
+        }
+
+        impl OwnedChunkValues for $owned_polars_type {})*
+        $(impl ChunkValues for $array_polars_type {
+            #[inline]
+            fn values_slice<'a>(_array: &'a Self::Array) -> Option<&'a [Self::Physical<'a>]> {
+                None
+            }
+
+            fn owned_value<'a>(_array: &Self::Array, _index: usize) -> Option<Self::Physical<'a>> {
+                unreachable!(
+                    "values of {} borrow from their chunk, which is never owned",
+                    stringify!($array_polars_type)
+                )
+            }
+        })*
+    };
+}
+
+impl_chunk_values!(
+    slice: Int8Type, Int16Type, Int32Type, Int64Type, UInt8Type, UInt16Type, UInt32Type, UInt64Type, Float32Type,
+    Float64Type;
+    owned_array: BooleanType;
+    array: StringType, BinaryType, BinaryOffsetType
+);
+
+/// Iterator over the values of a chunked array, which seeks over whole chunks in `nth()` and `nth_back()` instead of
+/// reading every skipped value. Numeric chunks are read directly from their values slice and the validity bitmap is
+/// only checked for chunks with null values. Clones share the chunks, so that they only copy the positions.
+pub struct ChunkedArrayIter<'a, T>
+where
+    T: ChunkValues,
+{
+    chunks: Arc<[Chunk<'a, T>]>,
+    front_chunk: usize,
+    front_index: usize,
+    back_chunk: usize,
+    back_index: usize,
+    len: usize,
+}
+
+/// Non-empty chunk of a chunked array, resolved once for all iterators over the chunked array
+struct Chunk<'a, T>
+where
+    T: ChunkValues,
+{
+    data: ChunkData<'a, T>,
+    offset: usize,
+    len: usize,
+}
+
+/// Values of a chunk, resolved so that reading a value takes a single branch
+enum ChunkData<'a, T>
+where
+    T: ChunkValues,
+{
+    /// Numeric chunk without null values, read from its values slice
+    Values(&'a [T::Physical<'a>]),
+    /// Chunk without null values and without a values slice
+    Array(&'a T::Array),
+    /// Chunk with null values, read after checking the validity bitmap
+    Nullable(&'a T::Array),
+    /// Chunk of a cast column, owned by the iterators over it
+    Owned(T::Array),
+}
+
+// implemented by hand, as deriving would require the dtype marker `T` to be `Clone`
+impl<T> Clone for ChunkedArrayIter<'_, T>
+where
+    T: ChunkValues,
+{
+    fn clone(&self) -> Self {
+        Self {
+            chunks: self.chunks.clone(),
+            front_chunk: self.front_chunk,
+            front_index: self.front_index,
+            back_chunk: self.back_chunk,
+            back_index: self.back_index,
+            len: self.len,
+        }
+    }
+}
+
+impl<'a, T> ChunkedArrayIter<'a, T>
+where
+    T: ChunkValues,
+{
+    pub fn new(chunked_array: &'a ChunkedArray<T>) -> Self {
+        let chunks = chunked_array
+            .downcast_iter()
+            .zip(chunked_array.iter_validities())
+            .map(|(array, validity)| {
+                let has_nulls = validity.is_some_and(|validity| validity.unset_bits() > 0);
+                match (has_nulls, T::values_slice(array)) {
+                    (true, _) => ChunkData::Nullable(array),
+                    (false, Some(values)) => ChunkData::Values(values),
+                    (false, None) => ChunkData::Array(array),
+                }
+            });
+
+        Self::from_chunks(chunks, chunked_array)
+    }
+
+    /// Creates an iterator owning the chunks of the chunked array, e.g. of a column cast by the row iterator
+    pub fn owned(chunked_array: &ChunkedArray<T>) -> Self
+    where
+        T: OwnedChunkValues,
+    {
+        let chunks = chunked_array
+            .downcast_iter()
+            .map(|array| ChunkData::Owned(array.clone()));
+
+        Self::from_chunks(chunks, chunked_array)
+    }
+
+    fn from_chunks(chunks: impl Iterator<Item = ChunkData<'a, T>>, chunked_array: &ChunkedArray<T>) -> Self {
+        let mut offset = 0;
+        let chunks: Arc<[Chunk<'a, T>]> = chunks
+            .zip(chunked_array.chunk_lengths())
+            .filter(|(_, len)| *len > 0)
+            .map(|(data, len)| {
+                offset += len;
+                Chunk {
+                    data,
+                    offset: offset - len,
+                    len,
+                }
+            })
+            .collect();
+
+        let back_chunk = chunks.len().saturating_sub(1);
+        let back_index = chunks.last().map(|chunk| chunk.len).unwrap_or(0);
+
+        Self {
+            chunks,
+            front_chunk: 0,
+            front_index: 0,
+            back_chunk,
+            back_index,
+            len: chunked_array.len(),
+        }
+    }
+
+    #[inline]
+    fn get(&self, chunk: usize, index: usize) -> Option<T::Physical<'a>> {
+        match &self.chunks[chunk].data {
+            // SAFETY: the values slice has the length of the chunk
+            ChunkData::Values(values) => Some(unsafe { values.get_unchecked(index) }.clone()),
+            // SAFETY: the index is always below the chunk length
+            ChunkData::Array(array) => Some(unsafe { array.value_unchecked(index) }),
+            // SAFETY: the index is always below the chunk length
+            ChunkData::Nullable(array) => unsafe { array.get_unchecked(index) },
+            ChunkData::Owned(array) => T::owned_value(array, index),
+        }
+    }
+
+    #[inline]
+    fn skip_front(&mut self, mut n: usize) {
+        self.len -= n;
+        loop {
+            let chunk_remaining = self.chunks[self.front_chunk].len - self.front_index;
+            if n < chunk_remaining {
+                self.front_index += n;
+                return;
+            }
+            n -= chunk_remaining;
+            self.front_chunk += 1;
+            self.front_index = 0;
+        }
+    }
+
+    #[inline]
+    fn skip_back(&mut self, mut n: usize) {
+        self.len -= n;
+        loop {
+            if n < self.back_index {
+                self.back_index -= n;
+                return;
+            }
+            n -= self.back_index;
+            self.back_chunk -= 1;
+            self.back_index = self.chunks[self.back_chunk].len;
+        }
+    }
+}
+
+impl<'a, T> Iterator for ChunkedArrayIter<'a, T>
+where
+    T: ChunkValues,
+{
+    type Item = Option<T::Physical<'a>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        if self.front_index == self.chunks[self.front_chunk].len {
+            self.front_chunk += 1;
+            self.front_index = 0;
+        }
+
+        let value = self.get(self.front_chunk, self.front_index);
+        self.front_index += 1;
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            self.len = 0;
+            return None;
+        }
+
+        self.skip_front(n);
+        self.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for ChunkedArrayIter<'_, T>
+where
+    T: ChunkValues,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+
+        if self.back_index == 0 {
+            self.back_chunk -= 1;
+            self.back_index = self.chunks[self.back_chunk].len;
+        }
+
+        self.back_index -= 1;
+        self.len -= 1;
+
+        Some(self.get(self.back_chunk, self.back_index))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.len {
+            self.len = 0;
+            return None;
+        }
+
+        self.skip_back(n);
+        self.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for ChunkedArrayIter<'_, T> where T: ChunkValues {}
+
+impl<T> ColumnIter for ChunkedArrayIter<'_, T>
+where
+    T: ChunkValues,
+{
+    #[inline]
+    fn value_at(&self, index: usize) -> Self::Item {
+        let chunk = self.chunks.partition_point(|chunk| chunk.offset + chunk.len <= index);
+        assert!(
+            chunk < self.chunks.len(),
+            "Row index {index} is out of bounds for the column"
+        );
+
+        self.get(chunk, index - self.chunks[chunk].offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    fn create_chunked_array() -> Int32Chunked {
+        let mut ca = Int32Chunked::new("values".into(), [Some(0), None, Some(2)]);
+        ca.append(&Int32Chunked::new("values".into(), [Some(3)])).unwrap();
+        ca.append(&Int32Chunked::new("values".into(), [None, Some(5), Some(6)]))
+            .unwrap();
+        ca
+    }
+
+    fn chunk_kinds<T>(iter: &ChunkedArrayIter<'_, T>) -> Vec<&'static str>
+    where
+        T: ChunkValues,
+    {
+        iter.chunks
+            .iter()
+            .map(|chunk| match chunk.data {
+                ChunkData::Values(_) => "values",
+                ChunkData::Array(_) => "array",
+                ChunkData::Nullable(_) => "nullable",
+                ChunkData::Owned(_) => "owned",
+            })
+            .collect()
+    }
+
+    #[test]
+    fn iter_should_return_same_values_as_polars_iter() {
+        let ca = create_chunked_array();
+
+        assert_eq!(3, ca.chunks().len());
+        assert_eq!(ca.iter().collect_vec(), ChunkedArrayIter::new(&ca).collect_vec());
+        assert_eq!(
+            ca.iter().rev().collect_vec(),
+            ChunkedArrayIter::new(&ca).rev().collect_vec()
+        );
+    }
+
+    #[test]
+    fn nth_should_seek_across_chunks() {
+        let ca = create_chunked_array();
+
+        let mut iter = ChunkedArrayIter::new(&ca);
+        assert_eq!(Some(Some(3)), iter.nth(3));
+        assert_eq!(3, iter.len());
+        assert_eq!(Some(Some(6)), iter.nth_back(0));
+        assert_eq!(Some(None), iter.nth_back(1));
+        assert_eq!(None, iter.next());
+
+        let mut iter = ChunkedArrayIter::new(&ca);
+        assert_eq!(Some(Some(5)), iter.nth_back(1));
+        assert_eq!(Some(Some(2)), iter.nth(2));
+        assert_eq!(None, iter.nth(2));
+        assert_eq!(None, iter.next_back());
+    }
+
+    #[test]
+    fn clone_should_share_the_chunks_and_keep_its_own_position() {
+        let ca = create_chunked_array();
+
+        let mut iter = ChunkedArrayIter::new(&ca);
+        assert_eq!(Some(Some(2)), iter.nth(2));
+        let mut clone = iter.clone();
+
+        assert!(Arc::ptr_eq(&iter.chunks, &clone.chunks));
+        assert_eq!(Some(Some(5)), clone.nth(2));
+        assert_eq!(Some(Some(3)), iter.next());
+    }
+
+    #[test]
+    fn owned_iter_should_return_same_values_after_the_chunked_array_is_dropped() {
+        let ca = create_chunked_array();
+        let expected = ca.iter().collect_vec();
+
+        let iter = ChunkedArrayIter::<'static, Int32Type>::owned(&ca);
+        drop(ca);
+
+        assert_eq!(vec!["owned", "owned", "owned"], chunk_kinds(&iter));
+        assert_eq!(Some(5), iter.value_at(5));
+        assert_eq!(
+            expected.iter().rev().copied().collect_vec(),
+            iter.clone().rev().collect_vec()
+        );
+        assert_eq!(expected, iter.collect_vec());
+    }
+
+    #[test]
+    fn value_at_should_read_values_of_all_chunks_regardless_of_position() {
+        let ca = create_chunked_array();
+
+        let mut iter = ChunkedArrayIter::new(&ca);
+        assert_eq!(Some(Some(5)), iter.nth(5));
+
+        assert_eq!(
+            ca.iter().collect_vec(),
+            (0..7).map(|index| iter.value_at(index)).collect_vec()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn value_at_should_panic_for_indices_out_of_bounds() {
+        let ca = create_chunked_array();
+
+        ChunkedArrayIter::new(&ca).value_at(7);
+    }
+
+    #[test]
+    fn iter_should_handle_both_ends_meeting() {
+        let ca = create_chunked_array();
+
+        let mut iter = ChunkedArrayIter::new(&ca);
+        let mut values = vec![];
+        while let Some(front) = iter.next() {
+            values.push(front);
+            if let Some(back) = iter.next_back() {
+                values.push(back);
+            }
+        }
+
+        assert_eq!(vec![Some(0), Some(6), None, Some(5), Some(2), None, Some(3)], values);
+    }
+
+    #[test]
+    fn iter_should_read_null_free_and_nullable_chunks() {
+        let mut ca = StringChunked::new("values".into(), ["a", "b"]);
+        ca.append(&StringChunked::new("values".into(), [None, Some("d")]))
+            .unwrap();
+        ca.append(&StringChunked::new("values".into(), ["e"])).unwrap();
+
+        let iter = ChunkedArrayIter::new(&ca);
+
+        assert_eq!(vec!["array", "nullable", "array"], chunk_kinds(&iter));
+        assert_eq!(ca.iter().collect_vec(), iter.collect_vec());
+    }
+
+    #[test]
+    fn iter_should_read_numeric_chunks_from_their_values_slice() {
+        let ca = create_chunked_array();
+
+        let iter = ChunkedArrayIter::new(&ca);
+
+        assert_eq!(vec!["nullable", "values", "nullable"], chunk_kinds(&iter));
+        assert!(matches!(iter.chunks[1].data, ChunkData::Values([3])));
+        assert_eq!(ca.iter().collect_vec(), iter.collect_vec());
+    }
+}
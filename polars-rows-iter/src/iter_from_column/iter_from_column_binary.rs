@@ -1,12 +1,24 @@
+use super::ChunkedArrayIter;
 use crate::*;
 use polars::prelude::*;
 
 impl<'a> IterFromColumn<'a> for &'a [u8] {
     type RawInner = &'a [u8];
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<&'a [u8]>> + 'a>> {
+    type Iter = BinaryIter<'a>;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter> {
         create_iter(column)
     }
 
+    fn accepted_dtypes() -> Vec<DataType> {
+        accepted_dtypes()
+    }
+
+    #[inline]
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<&'a [u8]>> {
+        get_raw(column, index)
+    }
+
     #[inline]
     fn get_value(polars_value: Option<&'a [u8]>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
     where
@@ -14,14 +26,30 @@ impl<'a> IterFromColumn<'a> for &'a [u8] {
     {
         polars_value.ok_or_else(|| <&[u8] as IterFromColumn<'a>>::unexpected_null_value_error(column_name))
     }
+
+    #[inline]
+    fn default_value() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl<'a> IterFromColumn<'a> for Option<&'a [u8]> {
     type RawInner = &'a [u8];
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<&'a [u8]>> + 'a>> {
+    type Iter = BinaryIter<'a>;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter> {
         create_iter(column)
     }
 
+    fn accepted_dtypes() -> Vec<DataType> {
+        accepted_dtypes()
+    }
+
+    #[inline]
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<&'a [u8]>> {
+        get_raw(column, index)
+    }
+
     #[inline]
     fn get_value(polars_value: Option<&'a [u8]>, _column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
     where
@@ -31,11 +59,78 @@ impl<'a> IterFromColumn<'a> for Option<&'a [u8]> {
     }
 }
 
-fn create_iter<'a>(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<&'a [u8]>> + 'a>> {
+/// Iterator over the values of a Binary or BinaryOffset column
+#[derive(Clone)]
+pub enum BinaryIter<'a> {
+    Binary(ChunkedArrayIter<'a, BinaryType>),
+    BinaryOffset(ChunkedArrayIter<'a, BinaryOffsetType>),
+}
+
+impl<'a> Iterator for BinaryIter<'a> {
+    type Item = Option<&'a [u8]>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            BinaryIter::Binary(iter) => iter.next(),
+            BinaryIter::BinaryOffset(iter) => iter.next(),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self {
+            BinaryIter::Binary(iter) => iter.nth(n),
+            BinaryIter::BinaryOffset(iter) => iter.nth(n),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            BinaryIter::Binary(iter) => iter.size_hint(),
+            BinaryIter::BinaryOffset(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl DoubleEndedIterator for BinaryIter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            BinaryIter::Binary(iter) => iter.next_back(),
+            BinaryIter::BinaryOffset(iter) => iter.next_back(),
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        match self {
+            BinaryIter::Binary(iter) => iter.nth_back(n),
+            BinaryIter::BinaryOffset(iter) => iter.nth_back(n),
+        }
+    }
+}
+
+impl ExactSizeIterator for BinaryIter<'_> {}
+
+impl ColumnIter for BinaryIter<'_> {
+    #[inline]
+    fn value_at(&self, index: usize) -> Self::Item {
+        match self {
+            BinaryIter::Binary(iter) => iter.value_at(index),
+            BinaryIter::BinaryOffset(iter) => iter.value_at(index),
+        }
+    }
+}
+
+fn accepted_dtypes() -> Vec<DataType> {
+    vec![DataType::Binary, DataType::BinaryOffset]
+}
+
+fn create_iter(column: &Column) -> PolarsResult<BinaryIter<'_>> {
     let column_name = column.name().as_str();
-    let iter: Box<dyn Iterator<Item = Option<&[u8]>>> = match column.dtype() {
-        DataType::Binary => Box::new(column.binary()?.iter()),
-        DataType::BinaryOffset => Box::new(column.binary_offset()?.iter()),
+    let iter = match column.dtype() {
+        DataType::Binary => BinaryIter::Binary(ChunkedArrayIter::new(column.binary()?)),
+        DataType::BinaryOffset => BinaryIter::BinaryOffset(ChunkedArrayIter::new(column.binary_offset()?)),
         dtype => {
             return Err(
                 polars_err!(SchemaMismatch: "Cannot get &[u8] from column '{column_name}' with dtype : {dtype}"),
@@ -46,6 +141,21 @@ fn create_iter<'a>(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = O
     Ok(iter)
 }
 
+fn get_raw(column: &Column, index: usize) -> PolarsResult<Option<&[u8]>> {
+    let column_name = column.name().as_str();
+    let value = match column.dtype() {
+        DataType::Binary => column.binary()?.get(index),
+        DataType::BinaryOffset => column.binary_offset()?.get(index),
+        dtype => {
+            return Err(
+                polars_err!(SchemaMismatch: "Cannot get &[u8] from column '{column_name}' with dtype : {dtype}"),
+            )
+        }
+    };
+
+    Ok(value)
+}
+
 #[cfg(test)]
 mod tests {
     const ROW_COUNT: usize = 64;
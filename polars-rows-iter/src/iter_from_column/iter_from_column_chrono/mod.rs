@@ -1,2 +1,21 @@
+use polars::prelude::*;
+
+use crate::RowsIterError;
+
 mod datetime;
 mod naivedatetime;
+
+fn create_row_error(cause: PolarsError, timestamp: i64, column_name: &str, row: usize) -> RowsIterError {
+    match cause {
+        PolarsError::OutOfBounds(_) => RowsIterError::OutOfBounds {
+            column: column_name.to_string(),
+            row,
+            value: timestamp.to_string(),
+        },
+        cause => RowsIterError::Conversion {
+            column: column_name.to_string(),
+            row,
+            cause,
+        },
+    }
+}
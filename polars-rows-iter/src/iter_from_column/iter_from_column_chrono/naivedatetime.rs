@@ -1,16 +1,31 @@
+use super::create_row_error;
+use crate::iter_from_column::ChunkedArrayIter;
 use crate::*;
 use chrono::{DateTime, NaiveDateTime};
 use polars::prelude::*;
 
 impl<'a> IterFromColumn<'a> for NaiveDateTime {
     type RawInner = i64;
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<i64>> + 'a>>
+    type Iter = ChunkedArrayIter<'a, Int64Type>;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter>
     where
         Self: Sized,
     {
         create_datetime_iter(column)
     }
 
+    fn accepted_dtypes() -> Vec<DataType> {
+        vec![DataType::Datetime(TimeUnit::Microseconds, None)]
+    }
+
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<i64>>
+    where
+        Self: Sized,
+    {
+        Ok(column.datetime()?.get(index))
+    }
+
     fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
     where
         Self: Sized,
@@ -20,17 +35,50 @@ impl<'a> IterFromColumn<'a> for NaiveDateTime {
 
         create_datetime(timestamp, column_name, dtype)
     }
+
+    #[inline]
+    fn default_value() -> Option<Self> {
+        Some(Self::default())
+    }
+
+    fn get_row_value(
+        polars_value: Option<i64>,
+        column_name: &str,
+        dtype: &DataType,
+        row: usize,
+    ) -> Result<Self, RowsIterError> {
+        let timestamp = polars_value.ok_or_else(|| RowsIterError::UnexpectedNull {
+            column: column_name.to_string(),
+            row,
+        })?;
+
+        create_datetime(timestamp, column_name, dtype)
+            .map_err(|cause| create_row_error(cause, timestamp, column_name, row))
+    }
 }
 
 impl<'a> IterFromColumn<'a> for Option<NaiveDateTime> {
     type RawInner = i64;
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<i64>> + 'a>>
+    type Iter = ChunkedArrayIter<'a, Int64Type>;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter>
     where
         Self: Sized,
     {
         create_datetime_iter(column)
     }
 
+    fn accepted_dtypes() -> Vec<DataType> {
+        vec![DataType::Datetime(TimeUnit::Microseconds, None)]
+    }
+
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<i64>>
+    where
+        Self: Sized,
+    {
+        Ok(column.datetime()?.get(index))
+    }
+
     fn get_value(polars_value: Option<i64>, column_name: &str, dtype: &DataType) -> PolarsResult<Self>
     where
         Self: Sized,
@@ -39,11 +87,24 @@ impl<'a> IterFromColumn<'a> for Option<NaiveDateTime> {
             .map(|timestamp| create_datetime(timestamp, column_name, dtype))
             .transpose()
     }
+
+    fn get_row_value(
+        polars_value: Option<i64>,
+        column_name: &str,
+        dtype: &DataType,
+        row: usize,
+    ) -> Result<Self, RowsIterError> {
+        polars_value
+            .map(|timestamp| {
+                create_datetime(timestamp, column_name, dtype)
+                    .map_err(|cause| create_row_error(cause, timestamp, column_name, row))
+            })
+            .transpose()
+    }
 }
 
-fn create_datetime_iter<'a>(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<i64>> + 'a>> {
-    let iter = column.datetime()?.iter();
-    Ok(Box::new(iter))
+fn create_datetime_iter(column: &Column) -> PolarsResult<ChunkedArrayIter<'_, Int64Type>> {
+    Ok(ChunkedArrayIter::new(column.datetime()?.physical()))
 }
 
 fn create_datetime(timestamp: i64, column_name: &str, dtype: &DataType) -> PolarsResult<NaiveDateTime> {
@@ -58,7 +119,7 @@ fn create_datetime(timestamp: i64, column_name: &str, dtype: &DataType) -> Polar
         TimeUnit::Microseconds => DateTime::from_timestamp_micros(timestamp).map(|dt|dt.naive_utc())
             .ok_or_else(|| polars_err!(OutOfBounds: "Value {timestamp} in column {column_name} is not a valid microseconds timestamp")),
         TimeUnit::Milliseconds => DateTime::from_timestamp_millis(timestamp).map(|dt|dt.naive_utc())
-            .ok_or_else(|| polars_err!(OutOfBounds: "Value {timestamp} in column {column_name} is not a valid nanoseconds timestamp")),
+            .ok_or_else(|| polars_err!(OutOfBounds: "Value {timestamp} in column {column_name} is not a valid milliseconds timestamp")),
     }
 }
 
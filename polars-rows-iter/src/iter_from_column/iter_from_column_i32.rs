@@ -3,10 +3,25 @@ use polars::prelude::*;
 
 impl<'a> IterFromColumn<'a> for i32 {
     type RawInner = i32;
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<i32>> + 'a>> {
+    type Iter = ChunkedArrayIter<'a, Int32Type>;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter> {
         create_iter(column)
     }
 
+    fn accepted_dtypes() -> Vec<DataType> {
+        accepted_dtypes()
+    }
+
+    fn create_cast_iter(column: &Column) -> Option<Self::Iter> {
+        create_cast_iter(column)
+    }
+
+    #[inline]
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<i32>> {
+        get_raw(column, index)
+    }
+
     #[inline]
     fn get_value(polars_value: Option<i32>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
     where
@@ -14,14 +29,34 @@ impl<'a> IterFromColumn<'a> for i32 {
     {
         polars_value.ok_or_else(|| <i32 as IterFromColumn<'a>>::unexpected_null_value_error(column_name))
     }
+
+    #[inline]
+    fn default_value() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl<'a> IterFromColumn<'a> for Option<i32> {
     type RawInner = i32;
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<i32>> + 'a>> {
+    type Iter = ChunkedArrayIter<'a, Int32Type>;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter> {
         create_iter(column)
     }
 
+    fn accepted_dtypes() -> Vec<DataType> {
+        accepted_dtypes()
+    }
+
+    fn create_cast_iter(column: &Column) -> Option<Self::Iter> {
+        create_cast_iter(column)
+    }
+
+    #[inline]
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<i32>> {
+        get_raw(column, index)
+    }
+
     #[inline]
     fn get_value(polars_value: Option<i32>, _column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
     where
@@ -31,11 +66,15 @@ impl<'a> IterFromColumn<'a> for Option<i32> {
     }
 }
 
-fn create_iter<'a>(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<i32>> + 'a>> {
+fn accepted_dtypes() -> Vec<DataType> {
+    vec![DataType::Int32, DataType::Date]
+}
+
+fn create_iter(column: &Column) -> PolarsResult<ChunkedArrayIter<'_, Int32Type>> {
     let column_name = column.name().as_str();
     let iter = match column.dtype() {
-        DataType::Int32 => Box::new(column.i32()?.iter()),
-        DataType::Date => Box::new(column.date()?.iter()),
+        DataType::Int32 => ChunkedArrayIter::new(column.i32()?),
+        DataType::Date => ChunkedArrayIter::new(column.date()?.physical()),
         dtype => {
             return Err(polars_err!(SchemaMismatch: "Cannot get i32 from column '{column_name}' with dtype : {dtype}"))
         }
@@ -44,6 +83,23 @@ fn create_iter<'a>(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = O
     Ok(iter)
 }
 
+fn create_cast_iter<'a>(column: &Column) -> Option<ChunkedArrayIter<'a, Int32Type>> {
+    Some(ChunkedArrayIter::owned(column.i32().ok()?))
+}
+
+fn get_raw(column: &Column, index: usize) -> PolarsResult<Option<i32>> {
+    let column_name = column.name().as_str();
+    let value = match column.dtype() {
+        DataType::Int32 => column.i32()?.get(index),
+        DataType::Date => column.date()?.get(index),
+        dtype => {
+            return Err(polars_err!(SchemaMismatch: "Cannot get i32 from column '{column_name}' with dtype : {dtype}"))
+        }
+    };
+
+    Ok(value)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -3,10 +3,25 @@ use polars::prelude::*;
 
 impl<'a> IterFromColumn<'a> for i64 {
     type RawInner = i64;
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<i64>> + 'a>> {
+    type Iter = ChunkedArrayIter<'a, Int64Type>;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter> {
         create_iter(column)
     }
 
+    fn accepted_dtypes() -> Vec<DataType> {
+        accepted_dtypes()
+    }
+
+    fn create_cast_iter(column: &Column) -> Option<Self::Iter> {
+        create_cast_iter(column)
+    }
+
+    #[inline]
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<i64>> {
+        get_raw(column, index)
+    }
+
     #[inline]
     fn get_value(polars_value: Option<i64>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
     where
@@ -14,14 +29,34 @@ impl<'a> IterFromColumn<'a> for i64 {
     {
         polars_value.ok_or_else(|| <i64 as IterFromColumn<'a>>::unexpected_null_value_error(column_name))
     }
+
+    #[inline]
+    fn default_value() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl<'a> IterFromColumn<'a> for Option<i64> {
     type RawInner = i64;
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<i64>> + 'a>> {
+    type Iter = ChunkedArrayIter<'a, Int64Type>;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter> {
         create_iter(column)
     }
 
+    fn accepted_dtypes() -> Vec<DataType> {
+        accepted_dtypes()
+    }
+
+    fn create_cast_iter(column: &Column) -> Option<Self::Iter> {
+        create_cast_iter(column)
+    }
+
+    #[inline]
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<i64>> {
+        get_raw(column, index)
+    }
+
     #[inline]
     fn get_value(polars_value: Option<i64>, _column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
     where
@@ -31,13 +66,22 @@ impl<'a> IterFromColumn<'a> for Option<i64> {
     }
 }
 
-fn create_iter<'a>(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<i64>> + 'a>> {
+fn accepted_dtypes() -> Vec<DataType> {
+    vec![
+        DataType::Int64,
+        DataType::Time,
+        DataType::Datetime(TimeUnit::Microseconds, None),
+        DataType::Duration(TimeUnit::Microseconds),
+    ]
+}
+
+fn create_iter(column: &Column) -> PolarsResult<ChunkedArrayIter<'_, Int64Type>> {
     let column_name = column.name().as_str();
     let iter = match column.dtype() {
-        DataType::Int64 => Box::new(column.i64()?.iter()),
-        DataType::Time => Box::new(column.as_materialized_series().time()?.iter()),
-        DataType::Datetime(_, _) => Box::new(column.datetime()?.iter()),
-        DataType::Duration(_) => Box::new(column.duration()?.iter()),
+        DataType::Int64 => ChunkedArrayIter::new(column.i64()?),
+        DataType::Time => ChunkedArrayIter::new(column.as_materialized_series().time()?.physical()),
+        DataType::Datetime(_, _) => ChunkedArrayIter::new(column.datetime()?.physical()),
+        DataType::Duration(_) => ChunkedArrayIter::new(column.duration()?.physical()),
         dtype => {
             return Err(polars_err!(SchemaMismatch: "Cannot get i64 from column '{column_name}' with dtype : {dtype}"))
         }
@@ -46,6 +90,25 @@ fn create_iter<'a>(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = O
     Ok(iter)
 }
 
+fn create_cast_iter<'a>(column: &Column) -> Option<ChunkedArrayIter<'a, Int64Type>> {
+    Some(ChunkedArrayIter::owned(column.i64().ok()?))
+}
+
+fn get_raw(column: &Column, index: usize) -> PolarsResult<Option<i64>> {
+    let column_name = column.name().as_str();
+    let value = match column.dtype() {
+        DataType::Int64 => column.i64()?.get(index),
+        DataType::Time => column.as_materialized_series().time()?.get(index),
+        DataType::Datetime(_, _) => column.datetime()?.get(index),
+        DataType::Duration(_) => column.duration()?.get(index),
+        dtype => {
+            return Err(polars_err!(SchemaMismatch: "Cannot get i64 from column '{column_name}' with dtype : {dtype}"))
+        }
+    };
+
+    Ok(value)
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -4,10 +4,21 @@ use polars::prelude::*;
 
 impl<'a> IterFromColumn<'a> for Series {
     type RawInner = Series;
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<Series>> + 'a>> {
+    type Iter = ListIter<'a>;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter> {
         create_iter(column)
     }
 
+    fn accepted_dtypes() -> Vec<DataType> {
+        accepted_dtypes()
+    }
+
+    #[inline]
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<Series>> {
+        get_raw(column, index)
+    }
+
     #[inline]
     fn get_value(polars_value: Option<Series>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
     where
@@ -15,14 +26,30 @@ impl<'a> IterFromColumn<'a> for Series {
     {
         polars_value.ok_or_else(|| <&'a str as IterFromColumn<'a>>::unexpected_null_value_error(column_name))
     }
+
+    #[inline]
+    fn default_value() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl<'a> IterFromColumn<'a> for Option<Series> {
     type RawInner = Series;
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<Series>> + 'a>> {
+    type Iter = ListIter<'a>;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter> {
         create_iter(column)
     }
 
+    fn accepted_dtypes() -> Vec<DataType> {
+        accepted_dtypes()
+    }
+
+    #[inline]
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<Series>> {
+        get_raw(column, index)
+    }
+
     #[inline]
     fn get_value(polars_value: Option<Series>, _column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
     where
@@ -32,10 +59,82 @@ impl<'a> IterFromColumn<'a> for Option<Series> {
     }
 }
 
-pub fn create_iter<'a>(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<Series>> + 'a>> {
-    let iter: Box<dyn Iterator<Item = Option<Series>>> = match column.dtype() {
-        // remove Box::new(), when https://github.com/rust-lang/rust/issues/65991 is stable
-        DataType::List(_) => Box::new(column.list()?.into_iter()),
+/// Iterator over the list values of a column, reading them by index to allow seeking
+#[derive(Clone)]
+pub struct ListIter<'a> {
+    chunked_array: &'a ListChunked,
+    front: usize,
+    back: usize,
+}
+
+impl<'a> ListIter<'a> {
+    fn new(chunked_array: &'a ListChunked) -> Self {
+        Self {
+            chunked_array,
+            front: 0,
+            back: chunked_array.len(),
+        }
+    }
+}
+
+impl Iterator for ListIter<'_> {
+    type Item = Option<Series>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        if self.front == self.back {
+            return None;
+        }
+
+        let value = self.chunked_array.get_as_series(self.front);
+        self.front += 1;
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for ListIter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.back = self.back.saturating_sub(n).max(self.front);
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+
+        Some(self.chunked_array.get_as_series(self.back))
+    }
+}
+
+impl ExactSizeIterator for ListIter<'_> {}
+
+impl ColumnIter for ListIter<'_> {
+    #[inline]
+    fn value_at(&self, index: usize) -> Self::Item {
+        self.chunked_array.get_as_series(index)
+    }
+}
+
+fn accepted_dtypes() -> Vec<DataType> {
+    vec![DataType::List(Box::new(DataType::Null))]
+}
+
+pub fn create_iter(column: &Column) -> PolarsResult<ListIter<'_>> {
+    let iter = match column.dtype() {
+        DataType::List(_) => ListIter::new(column.list()?),
         dtype => {
             let column_name = column.name().as_str();
             return Err(
@@ -47,6 +146,20 @@ pub fn create_iter<'a>(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item
     Ok(iter)
 }
 
+pub fn get_raw(column: &Column, index: usize) -> PolarsResult<Option<Series>> {
+    let value = match column.dtype() {
+        DataType::List(_) => column.list()?.get_as_series(index),
+        dtype => {
+            let column_name = column.name().as_str();
+            return Err(
+                polars_err!(SchemaMismatch: "Cannot get Series from column '{column_name}' with dtype : {dtype}"),
+            );
+        }
+    };
+
+    Ok(value)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
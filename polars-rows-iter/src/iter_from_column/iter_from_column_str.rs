@@ -4,10 +4,21 @@ use polars::prelude::*;
 
 impl<'a> IterFromColumn<'a> for &'a str {
     type RawInner = &'a str;
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<&'a str>> + 'a>> {
+    type Iter = StrIter<'a>;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter> {
         create_iter(column)
     }
 
+    fn accepted_dtypes() -> Vec<DataType> {
+        accepted_dtypes()
+    }
+
+    #[inline]
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<&'a str>> {
+        get_raw(column, index)
+    }
+
     #[inline]
     fn get_value(polars_value: Option<&'a str>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
     where
@@ -15,14 +26,30 @@ impl<'a> IterFromColumn<'a> for &'a str {
     {
         polars_value.ok_or_else(|| <&'a str as IterFromColumn<'a>>::unexpected_null_value_error(column_name))
     }
+
+    #[inline]
+    fn default_value() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl<'a> IterFromColumn<'a> for Option<&'a str> {
     type RawInner = &'a str;
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<&'a str>> + 'a>> {
+    type Iter = StrIter<'a>;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter> {
         create_iter(column)
     }
 
+    fn accepted_dtypes() -> Vec<DataType> {
+        accepted_dtypes()
+    }
+
+    #[inline]
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<&'a str>> {
+        get_raw(column, index)
+    }
+
     #[inline]
     fn get_value(polars_value: Option<&'a str>, _column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
     where
@@ -32,20 +59,154 @@ impl<'a> IterFromColumn<'a> for Option<&'a str> {
     }
 }
 
-fn create_str_iter<'a>(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<&'a str>> + 'a>> {
-    Ok(Box::new(column.str()?.iter()))
+/// Iterator over the values of a String column, or the mapped values of a Categorical/Enum column
+#[derive(Clone)]
+pub enum StrIter<'a> {
+    String(ChunkedArrayIter<'a, StringType>),
+    #[cfg(feature = "dtype-categorical")]
+    Categorical(CategoricalIter<'a>),
+}
+
+impl<'a> Iterator for StrIter<'a> {
+    type Item = Option<&'a str>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            StrIter::String(iter) => iter.next(),
+            #[cfg(feature = "dtype-categorical")]
+            StrIter::Categorical(iter) => iter.next(),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self {
+            StrIter::String(iter) => iter.nth(n),
+            #[cfg(feature = "dtype-categorical")]
+            StrIter::Categorical(iter) => iter.nth(n),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            StrIter::String(iter) => iter.size_hint(),
+            #[cfg(feature = "dtype-categorical")]
+            StrIter::Categorical(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl DoubleEndedIterator for StrIter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            StrIter::String(iter) => iter.next_back(),
+            #[cfg(feature = "dtype-categorical")]
+            StrIter::Categorical(iter) => iter.next_back(),
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        match self {
+            StrIter::String(iter) => iter.nth_back(n),
+            #[cfg(feature = "dtype-categorical")]
+            StrIter::Categorical(iter) => iter.nth_back(n),
+        }
+    }
+}
+
+impl ExactSizeIterator for StrIter<'_> {}
+
+impl ColumnIter for StrIter<'_> {
+    #[inline]
+    fn value_at(&self, index: usize) -> Self::Item {
+        match self {
+            StrIter::String(iter) => iter.value_at(index),
+            #[cfg(feature = "dtype-categorical")]
+            StrIter::Categorical(iter) => iter.value_at(index),
+        }
+    }
+}
+
+#[cfg(feature = "dtype-categorical")]
+fn create_cat_iter(column: &Column) -> PolarsResult<CategoricalIter<'_>> {
+    let ca = column.categorical()?;
+    Ok(CategoricalIter {
+        rev_map: ca.get_rev_map(),
+        iter: ChunkedArrayIter::new(ca.physical()),
+    })
+}
+
+/// Iterator over the values of a Categorical/Enum column, mapped by the reverse mapping of the column
+#[cfg(feature = "dtype-categorical")]
+#[derive(Clone)]
+pub struct CategoricalIter<'a> {
+    rev_map: &'a RevMapping,
+    iter: ChunkedArrayIter<'a, UInt32Type>,
 }
 
 #[cfg(feature = "dtype-categorical")]
-fn create_cat_iter<'a>(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<&'a str>> + 'a>> {
-    Ok(Box::new(column.categorical()?.iter_str()))
+impl<'a> Iterator for CategoricalIter<'a> {
+    type Item = Option<&'a str>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.iter.next()?;
+        Some(idx.map(|idx| self.rev_map.get(idx)))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let idx = self.iter.nth(n)?;
+        Some(idx.map(|idx| self.rev_map.get(idx)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
-pub fn create_iter<'a>(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<&'a str>> + 'a>> {
+#[cfg(feature = "dtype-categorical")]
+impl DoubleEndedIterator for CategoricalIter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let idx = self.iter.next_back()?;
+        Some(idx.map(|idx| self.rev_map.get(idx)))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let idx = self.iter.nth_back(n)?;
+        Some(idx.map(|idx| self.rev_map.get(idx)))
+    }
+}
+
+#[cfg(feature = "dtype-categorical")]
+impl ExactSizeIterator for CategoricalIter<'_> {}
+
+#[cfg(feature = "dtype-categorical")]
+impl ColumnIter for CategoricalIter<'_> {
+    #[inline]
+    fn value_at(&self, index: usize) -> Self::Item {
+        self.iter.value_at(index).map(|idx| self.rev_map.get(idx))
+    }
+}
+
+pub fn accepted_dtypes() -> Vec<DataType> {
+    vec![
+        DataType::String,
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(None, CategoricalOrdering::Physical),
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Enum(None, CategoricalOrdering::Physical),
+    ]
+}
+
+pub fn create_iter(column: &Column) -> PolarsResult<StrIter<'_>> {
     let iter = match column.dtype() {
-        DataType::String => create_str_iter(column)?,
+        DataType::String => StrIter::String(ChunkedArrayIter::new(column.str()?)),
         #[cfg(feature = "dtype-categorical")]
-        DataType::Categorical(_, _) | DataType::Enum(_, _) => create_cat_iter(column)?,
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => StrIter::Categorical(create_cat_iter(column)?),
         dtype => {
             let column_name = column.name().as_str();
             return Err(
@@ -58,6 +219,30 @@ pub fn create_iter<'a>(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item
     Ok(iter)
 }
 
+#[cfg(feature = "dtype-categorical")]
+fn get_cat_raw(column: &Column, index: usize) -> PolarsResult<Option<&str>> {
+    let ca = column.categorical()?;
+    let rev_map = ca.get_rev_map();
+    Ok(ca.physical().get(index).map(|idx| rev_map.get(idx)))
+}
+
+pub fn get_raw(column: &Column, index: usize) -> PolarsResult<Option<&str>> {
+    let value = match column.dtype() {
+        DataType::String => column.str()?.get(index),
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => get_cat_raw(column, index)?,
+        dtype => {
+            let column_name = column.name().as_str();
+            return Err(
+                polars_err!(SchemaMismatch: "Cannot get &str from column '{column_name}' with dtype '{dtype}'.\
+                                             Make sure to enable 'dtype-categorical' feature for 'Categorical' and 'Enum' dtypes."),
+            );
+        }
+    };
+
+    Ok(value)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -1,14 +1,27 @@
-use super::iter_from_column_str::create_iter;
+use super::iter_from_column_str::{accepted_dtypes, create_iter, get_raw};
 use super::*;
 use iter_from_column_trait::IterFromColumn;
 use polars::prelude::*;
 
+use crate::RowsIterError;
+
 impl<'a> IterFromColumn<'a> for String {
     type RawInner = &'a str;
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<&'a str>> + 'a>> {
+    type Iter = StrIter<'a>;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter> {
         create_iter(column)
     }
 
+    fn accepted_dtypes() -> Vec<DataType> {
+        accepted_dtypes()
+    }
+
+    #[inline]
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<&'a str>> {
+        get_raw(column, index)
+    }
+
     #[inline]
     fn get_value(polars_value: Option<&'a str>, column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
     where
@@ -18,14 +31,48 @@ impl<'a> IterFromColumn<'a> for String {
             .ok_or_else(|| <&'a str as IterFromColumn<'a>>::unexpected_null_value_error(column_name))?
             .to_string())
     }
+
+    #[inline]
+    fn default_value() -> Option<Self> {
+        Some(Self::default())
+    }
+
+    #[inline]
+    fn update_row_value(
+        target: &mut Self,
+        polars_value: Option<&'a str>,
+        column_name: &str,
+        dtype: &DataType,
+        row: usize,
+    ) -> Result<(), RowsIterError> {
+        match polars_value {
+            Some(value) => {
+                target.clear();
+                target.push_str(value);
+                Ok(())
+            }
+            None => Self::get_row_value(None, column_name, dtype, row).map(|_| ()),
+        }
+    }
 }
 
 impl<'a> IterFromColumn<'a> for Option<String> {
     type RawInner = &'a str;
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<&'a str>> + 'a>> {
+    type Iter = StrIter<'a>;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter> {
         create_iter(column)
     }
 
+    fn accepted_dtypes() -> Vec<DataType> {
+        accepted_dtypes()
+    }
+
+    #[inline]
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<&'a str>> {
+        get_raw(column, index)
+    }
+
     #[inline]
     fn get_value(polars_value: Option<&'a str>, _column_name: &str, _dtype: &DataType) -> PolarsResult<Self>
     where
@@ -33,6 +80,24 @@ impl<'a> IterFromColumn<'a> for Option<String> {
     {
         Ok(polars_value.map(|s| s.to_string()))
     }
+
+    #[inline]
+    fn update_row_value(
+        target: &mut Self,
+        polars_value: Option<&'a str>,
+        _column_name: &str,
+        _dtype: &DataType,
+        _row: usize,
+    ) -> Result<(), RowsIterError> {
+        match (target, polars_value) {
+            (Some(target), Some(value)) => {
+                target.clear();
+                target.push_str(value);
+            }
+            (target, value) => *target = value.map(|s| s.to_string()),
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -1,8 +1,52 @@
 use polars::prelude::*;
 
+use crate::{dtype_matches_any, DoubleEndedExactSizeIterator, RowsIterError};
+
+/// Column iterator which also reads values by row index, used by row accessors to resolve the chunks of a column once
+pub trait ColumnIter: DoubleEndedExactSizeIterator + Clone {
+    /// Reads the value at the given row index of the column, regardless of the position of the iterator. Panics if the
+    /// index is out of bounds.
+    fn value_at(&self, index: usize) -> Self::Item;
+}
+
 pub trait IterFromColumn<'a> {
     type RawInner;
-    fn create_iter(column: &'a Column) -> PolarsResult<Box<dyn Iterator<Item = Option<Self::RawInner>> + 'a>>
+
+    /// Concrete column iterator, so that row iterators are monomorphised without virtual calls per value. Row iterators
+    /// are `Send`, so they can be moved to other threads, as long as all column iterators are.
+    type Iter: ColumnIter<Item = Option<Self::RawInner>> + Send + 'a;
+
+    fn create_iter(column: &'a Column) -> PolarsResult<Self::Iter>
+    where
+        Self: Sized;
+
+    /// Polars dtypes supported by `create_iter()`. Parametrized dtypes like `Datetime(..)` or `List(..)` are listed once
+    /// with placeholder parameters and stand for the dtype with any parameters.
+    fn accepted_dtypes() -> Vec<DataType>
+    where
+        Self: Sized;
+
+    /// Checks if a column of the given dtype is supported, without creating the column iterator
+    fn accepts_dtype(dtype: &DataType) -> bool
+    where
+        Self: Sized,
+    {
+        dtype_matches_any(&Self::accepted_dtypes(), dtype)
+    }
+
+    /// Creates the column iterator over a column cast to the first of `accepted_dtypes()` by `rows_iter_cast()` or
+    /// `#[rows(cast)]`. The iterator owns the chunks of the cast column instead of borrowing them from the dataframe, so
+    /// only types whose raw values do not borrow from the column support casts, like the numeric and boolean types.
+    /// Returns None if the type does not support casts, columns of other dtypes are then reported as dtype mismatch.
+    fn create_cast_iter(_column: &Column) -> Option<Self::Iter>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Reads the raw value at the given row index of the column, the index must be in bounds
+    fn get_raw(column: &'a Column, index: usize) -> PolarsResult<Option<Self::RawInner>>
     where
         Self: Sized;
 
@@ -10,6 +54,59 @@ pub trait IterFromColumn<'a> {
     where
         Self: Sized;
 
+    /// Value of a mandatory field for a None/null value with `NullPolicy::UseDefault` set on iterator creation, or None
+    /// if the type has no default value and fails like with `NullPolicy::Error`. Fields with
+    /// `#[column(on_null = "default")]` use the `Default` implementation of the field type instead.
+    #[inline]
+    fn default_value() -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Converts the raw value of the given row like `get_value()`, but returns a structured error pointing to the row
+    #[inline]
+    fn get_row_value(
+        polars_value: Option<Self::RawInner>,
+        column_name: &str,
+        dtype: &DataType,
+        row: usize,
+    ) -> Result<Self, RowsIterError>
+    where
+        Self: Sized,
+    {
+        let is_null = polars_value.is_none();
+        Self::get_value(polars_value, column_name, dtype).map_err(|cause| match is_null {
+            true => RowsIterError::UnexpectedNull {
+                column: column_name.to_string(),
+                row,
+            },
+            false => RowsIterError::Conversion {
+                column: column_name.to_string(),
+                row,
+                cause,
+            },
+        })
+    }
+
+    /// Converts the raw value of the given row like `get_row_value()`, but overwrites the given value in place, so that
+    /// owned types can reuse their allocation. Used by `TryRowsIterator::try_next_into()`.
+    #[inline]
+    fn update_row_value(
+        target: &mut Self,
+        polars_value: Option<Self::RawInner>,
+        column_name: &str,
+        dtype: &DataType,
+        row: usize,
+    ) -> Result<(), RowsIterError>
+    where
+        Self: Sized,
+    {
+        *target = Self::get_row_value(polars_value, column_name, dtype, row)?;
+        Ok(())
+    }
+
     #[inline]
     fn unexpected_null_value_error(column_name: &str) -> PolarsError {
         polars_err!(SchemaMismatch: "Found unexpected None/null value in column {column_name} with mandatory values!")
@@ -1,3 +1,4 @@
+mod chunked_array_iter;
 mod iter_from_column_binary;
 #[cfg(feature = "chrono")]
 mod iter_from_column_chrono;
@@ -9,4 +10,10 @@ mod iter_from_column_str;
 mod iter_from_column_string;
 mod iter_from_column_trait;
 
-pub use iter_from_column_trait::IterFromColumn;
+pub use chunked_array_iter::{ChunkValues, ChunkedArrayIter, OwnedChunkValues};
+pub use iter_from_column_binary::BinaryIter;
+pub use iter_from_column_series::ListIter;
+#[cfg(feature = "dtype-categorical")]
+pub use iter_from_column_str::CategoricalIter;
+pub use iter_from_column_str::StrIter;
+pub use iter_from_column_trait::{ColumnIter, IterFromColumn};
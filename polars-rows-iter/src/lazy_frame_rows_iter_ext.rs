@@ -0,0 +1,219 @@
+use polars::prelude::*;
+
+use crate::{BatchedRowsIter, FromDataFrameRow, OwnedRowsIter, RowsIterOptions};
+
+/// Row iteration over `LazyFrame`s, which selects only the columns required by the row struct before collecting
+pub trait LazyFrameRowsIterExt {
+    fn rows_iter<T>(self) -> PolarsResult<OwnedRowsIter<T>>
+    where
+        T: for<'a> FromDataFrameRow<'a> + 'static;
+
+    fn rows_iter_cast<T>(self) -> PolarsResult<OwnedRowsIter<T>>
+    where
+        T: for<'a> FromDataFrameRow<'a> + 'static;
+
+    fn rows_iter_batched<T>(self, batch_size: usize) -> PolarsResult<BatchedRowsIter<T>>
+    where
+        T: for<'a> FromDataFrameRow<'a> + 'static;
+}
+
+impl LazyFrameRowsIterExt for LazyFrame {
+    /// Selects the columns of the row struct, collects the query and creates a row iterator owning the collected
+    /// dataframe. Row structs with borrowed field types like `&str` are not supported, as the rows would outlive the
+    /// dataframe.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///#[derive(PartialEq)] // for assert_eq
+    ///struct MyRow {
+    ///    id: i32,
+    ///    name: String,
+    ///}
+    ///
+    ///    let lf = df!(
+    ///        "id" => [1i32, 2],
+    ///        "name" => ["a", "b"],
+    ///        "unused" => [1.0f64, 2.0]
+    ///    ).unwrap().lazy();
+    ///
+    ///    let rows = lf
+    ///        .filter(col("id").gt(lit(1)))
+    ///        .rows_iter::<MyRow>()
+    ///        .unwrap()
+    ///        .collect::<PolarsResult<Vec<MyRow>>>()
+    ///        .unwrap();
+    ///
+    ///    assert_eq!(rows, [MyRow { id: 2, name: "b".to_string() }]);
+    ///```
+    fn rows_iter<T>(self) -> PolarsResult<OwnedRowsIter<T>>
+    where
+        T: for<'a> FromDataFrameRow<'a> + 'static,
+    {
+        collect_rows_iter(self, RowsIterOptions::default())
+    }
+
+    /// Like `rows_iter()`, but casts numeric columns to the dtype of the field type like
+    /// `DataframeRowsIterExt::rows_iter_cast()`
+    fn rows_iter_cast<T>(self) -> PolarsResult<OwnedRowsIter<T>>
+    where
+        T: for<'a> FromDataFrameRow<'a> + 'static,
+    {
+        let options = RowsIterOptions {
+            cast: true,
+            ..Default::default()
+        };
+        collect_rows_iter(self, options)
+    }
+
+    /// Selects the columns of the row struct and iterates over the rows of the query in batches of `batch_size` rows.
+    /// Unlike `rows_iter()`, the query runs once on the streaming engine into a temporary file, which is memory mapped
+    /// and iterated batch by batch, so the result does not need to fit into memory.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(FromDataFrameRow)]
+    ///struct MyRow {
+    ///    id: i32,
+    ///    name: String,
+    ///}
+    ///
+    ///    let lf = df!(
+    ///        "id" => [1i32, 2, 3, 4, 5],
+    ///        "name" => ["a", "b", "c", "d", "e"]
+    ///    ).unwrap().lazy();
+    ///
+    ///    let ids = lf
+    ///        .rows_iter_batched::<MyRow>(2)
+    ///        .unwrap()
+    ///        .map(|row| row.map(|row| row.id))
+    ///        .collect::<PolarsResult<Vec<i32>>>()
+    ///        .unwrap();
+    ///
+    ///    assert_eq!(ids, [1, 2, 3, 4, 5]);
+    ///```
+    fn rows_iter_batched<T>(self, batch_size: usize) -> PolarsResult<BatchedRowsIter<T>>
+    where
+        T: for<'a> FromDataFrameRow<'a> + 'static,
+    {
+        let lazy_frame = select_row_columns::<T>(self)?;
+        BatchedRowsIter::new(lazy_frame, RowsIterOptions::default(), batch_size)
+    }
+}
+
+fn collect_rows_iter<T>(lazy_frame: LazyFrame, options: RowsIterOptions) -> PolarsResult<OwnedRowsIter<T>>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    let dataframe = select_row_columns::<T>(lazy_frame)?.collect()?;
+
+    OwnedRowsIter::new(dataframe, options)
+}
+
+fn select_row_columns<T>(mut lazy_frame: LazyFrame) -> PolarsResult<LazyFrame>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    let schema = lazy_frame.collect_schema()?;
+
+    // missing columns are left out, to be reported together with all other schema errors on iterator creation
+    let mut column_names = vec![];
+    for column in T::expected_schema().into_iter().map(|column| column.column) {
+        if schema.contains(column) && !column_names.contains(&column) {
+            column_names.push(column);
+        }
+    }
+
+    // selecting no columns would return no rows, so a row struct with only `#[row_index]` fields selects the first
+    // column to keep the height of the query
+    if column_names.is_empty() {
+        column_names.extend(schema.iter_names().take(1).map(|name| name.as_str()));
+    }
+
+    let columns = column_names.into_iter().map(col).collect::<Vec<_>>();
+
+    Ok(lazy_frame.select(columns))
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::prelude::*;
+
+    use crate::*;
+
+    #[derive(Debug, PartialEq, FromDataFrameRow)]
+    struct TestRow {
+        id: i32,
+        #[column("label")]
+        name: String,
+        value: Option<f64>,
+    }
+
+    fn create_lazy_frame() -> LazyFrame {
+        df!(
+            "id" => [1i64, 2, 3],
+            "label" => ["a", "b", "c"],
+            "value" => [Some(1.0f64), None, Some(3.0)],
+            "unused" => ["x", "y", "z"]
+        )
+        .unwrap()
+        .lazy()
+    }
+
+    #[test]
+    fn rows_iter_should_select_the_struct_columns_and_cast_on_request() {
+        assert!(create_lazy_frame().rows_iter::<TestRow>().is_err());
+
+        let mut iter = create_lazy_frame().rows_iter_cast::<TestRow>().unwrap();
+
+        assert_eq!(3, iter.len());
+        assert_eq!(
+            TestRow {
+                id: 3,
+                name: "c".to_string(),
+                value: Some(3.0)
+            },
+            iter.next_back().unwrap().unwrap()
+        );
+        assert_eq!(
+            TestRow {
+                id: 1,
+                name: "a".to_string(),
+                value: Some(1.0)
+            },
+            iter.next().unwrap().unwrap()
+        );
+    }
+
+    #[test]
+    fn rows_iter_should_report_all_missing_columns() {
+        let error = create_lazy_frame()
+            .select([col("id")])
+            .rows_iter_cast::<TestRow>()
+            .err()
+            .unwrap();
+
+        assert!(matches!(error, PolarsError::ColumnNotFound(_)));
+        assert!(error.to_string().contains("Column 'label' of field 'name' not found"));
+        assert!(error.to_string().contains("Column 'value' of field 'value' not found"));
+    }
+
+    #[test]
+    fn rows_iter_should_keep_the_rows_of_structs_without_columns() {
+        #[derive(Debug, FromDataFrameRow)]
+        struct IndexRow {
+            #[row_index]
+            index: usize,
+        }
+
+        let indices = create_lazy_frame()
+            .rows_iter::<IndexRow>()
+            .unwrap()
+            .map(|row| row.unwrap().index)
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![0, 1, 2], indices);
+    }
+}
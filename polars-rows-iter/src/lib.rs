@@ -46,6 +46,39 @@
 //! ```
 //! Every row is wrapped with a PolarsError, in case of an unexpected null value the row creation fails and the iterator
 //! returns an Err(...) for the row. One can decide to cancel the iteration or to skip the affected row.
+//! A column of a mandatory (non-`Option`) field which contains null values only fails already on iterator creation.
+//! Alternatively rows with null values can be skipped or filled with default values, either for all fields with
+//! `rows_iter_with_null_policy()` or per field with `#[column(on_null = "skip")]` and `#[column(on_null = "default")]`.
+//! Missing columns and dtype mismatches of all fields are reported together in a single error on iterator creation,
+//! including suggestions for misspelled column names.
+//! The columns expected by a row struct, with their accepted dtypes and nullability, are described by
+//! `MyRow::expected_schema()`. `MyRow::validate_schema(&schema)` checks a schema, e.g. of a file or a `LazyFrame`,
+//! without reading any data.
+//! Columns with a different numeric dtype than the field type, like `Int64` for an `i32` field, are cast with
+//! `rows_iter_cast()` or for all iterators of a row struct with the `#[rows(cast)]` attribute.
+//! `df.rows_iter_indexed::<MyRow>()` yields rows together with their dataframe index, which is also read into fields
+//! with the `#[row_index]` attribute.
+//! `df.rows_iter_filtered::<MyRow>(&mask)` and `#[rows(filter = "column")]` iterate only the rows selected by a boolean
+//! mask, without converting the values of the other rows.
+//! `df.rows_iter_range::<MyRow>(start..end)` iterates a range of rows, seeking to its start without reading the rows
+//! before.
+//! `df.rows_batches::<MyRow>(batch_size)` yields vectors of rows together with the errors of their failed rows, e.g.
+//! for bulk inserts into databases.
+//! `df.group_rows_iter::<MyKey, MyRow>(["key"])` yields the key of every group with a row iterator over its rows,
+//! selected by the group indices of polars without creating a dataframe per group.
+//! `df.for_each_row_mut()` reuses a single row instance, overwriting owned fields like `String` in place.
+//! `df.into_rows_iter::<MyRow>()` moves the dataframe into the iterator, which can be returned from functions or moved
+//! to other threads. Like all owning iterators, it needs owned field types like `String`.
+//! All row iterators and row accessors are `Send`, so rows can be decoded on one thread and processed on another.
+//! With the `lazy` feature, rows of a `LazyFrame` are iterated by `lf.rows_iter::<MyRow>()`, which selects only the
+//! columns of the row struct and owns the collected dataframe.
+//! `lf.rows_iter_batched::<MyRow>(batch_size)` runs the query once on the streaming engine into a temporary file and
+//! iterates over it in batches of `batch_size` rows, for query results larger than memory.
+//! With the `rayon` feature, `df.par_rows_iter::<MyRow>()` returns an indexed parallel iterator, which splits the rows
+//! into ranges decoded on the rayon thread pool.
+//! With the `async` feature, `df.rows_stream::<MyRow>(batch_size)` returns a `futures::Stream` of batches like
+//! `rows_batches()`, which yields to the executor after every batch. Streams of dataframes are turned into batch
+//! streams in the same way.
 //!
 //! ## Supported types
 //!
@@ -92,14 +125,61 @@
 //! ## Limitations
 //! * No generics in row structs supported
 
+// lets the code generated by the derive macro refer to `::polars_rows_iter` within this crate as well
+extern crate self as polars_rows_iter;
+
+#[cfg(feature = "lazy")]
+mod batched_rows_iter;
 mod dataframe_rows_iter_ext;
+mod double_ended_exact_size_iterator;
+mod dyn_rows_iter;
+mod expected_schema;
 mod from_dataframe_row;
+mod group_rows_iter;
+mod indexed_rows_iter;
+mod iter_column;
 mod iter_from_column;
+#[cfg(feature = "lazy")]
+mod lazy_frame_rows_iter_ext;
+mod null_policy;
+mod owned_rows_iter;
+#[cfg(feature = "rayon")]
+mod par_rows_iter;
+mod row_accessor;
+mod row_cursor;
+mod rows_batches;
+mod rows_iter_error;
+mod rows_iter_options;
+#[cfg(feature = "async")]
+mod rows_stream;
+mod schema_resolver;
 
+#[cfg(feature = "lazy")]
+pub use batched_rows_iter::*;
 pub use dataframe_rows_iter_ext::*;
+pub use double_ended_exact_size_iterator::*;
+pub use dyn_rows_iter::*;
+pub use expected_schema::*;
 pub use from_dataframe_row::*;
+pub use group_rows_iter::*;
+pub use indexed_rows_iter::*;
+pub use iter_column::*;
 pub use iter_from_column::*;
+#[cfg(feature = "lazy")]
+pub use lazy_frame_rows_iter_ext::*;
+pub use null_policy::*;
+pub use owned_rows_iter::*;
+#[cfg(feature = "rayon")]
+pub use par_rows_iter::*;
 pub use polars_rows_iter_derive::FromDataFrameRow;
+pub use row_accessor::*;
+pub use row_cursor::*;
+pub use rows_batches::*;
+pub use rows_iter_error::*;
+pub use rows_iter_options::*;
+#[cfg(feature = "async")]
+pub use rows_stream::*;
+pub use schema_resolver::*;
 
 #[cfg(test)]
 pub mod shared_test_helpers;
@@ -0,0 +1,24 @@
+use crate::RowsIterError;
+
+/// Defines how a None/null value in a column of a mandatory (non-`Option`) field is handled.
+/// Can be set for all fields on iterator creation and overridden per field with `#[column(on_null = "skip")]` or
+/// `#[column(on_null = "default")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NullPolicy {
+    /// The row creation fails with an error
+    #[default]
+    Error,
+    /// The row is skipped by the iterator
+    SkipRow,
+    /// The default value of the field type is used. Fields with `on_null = "default"` use the `Default`
+    /// implementation of the field type, other fields the value of `IterFromColumn::default_value()`, which is
+    /// provided by all supported types.
+    UseDefault,
+}
+
+/// Error of a single row, together with the dataframe index of the row
+#[derive(Debug)]
+pub struct RowError {
+    pub row_index: usize,
+    pub error: RowsIterError,
+}
@@ -0,0 +1,159 @@
+use std::{collections::HashMap, sync::Arc};
+
+use polars::prelude::*;
+
+use crate::{FromDataFrameRow, RowIndexIterator, RowsIterError, RowsIterOptions, TryRowsIterator};
+
+/// Row iterator owning the dataframe it iterates over, for row structs without lifetime, i.e. with owned field types
+/// like `String` instead of `&str`. Created by `DataframeRowsIterExt::into_rows_iter()` or
+/// `LazyFrameRowsIterExt::rows_iter()`.
+pub struct OwnedRowsIter<T>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    // declared before the dataframe to be dropped first, as it borrows from the dataframe
+    iter: <T as FromDataFrameRow<'static>>::Iter,
+    _dataframe: Arc<DataFrame>,
+}
+
+impl<T> OwnedRowsIter<T>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    /// Creates a row iterator owning the dataframe
+    pub(crate) fn new(dataframe: impl Into<Arc<DataFrame>>, options: RowsIterOptions) -> PolarsResult<Self> {
+        let dataframe = dataframe.into();
+
+        // SAFETY: the dataframe is kept alive by the Arc owned by this iterator and its heap allocation does not move
+        // with the iterator. The reference is only held by the inner iterator, which is private and dropped before
+        // the Arc. `T` implements `FromDataFrameRow` for any lifetime, so its implementation cannot rely on the
+        // reference being `'static` and stash it elsewhere, and `T: 'static` keeps the rows from borrowing from it.
+        let dataframe_ref: &'static DataFrame = unsafe { &*Arc::as_ptr(&dataframe) };
+        let iter = T::from_dataframe_with_options(dataframe_ref, HashMap::new(), options)?;
+
+        Ok(Self {
+            iter,
+            _dataframe: dataframe,
+        })
+    }
+}
+
+impl<T> Iterator for OwnedRowsIter<T>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    type Item = PolarsResult<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for OwnedRowsIter<T>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth_back(n)
+    }
+}
+
+impl<T> ExactSizeIterator for OwnedRowsIter<T> where T: for<'a> FromDataFrameRow<'a> + 'static {}
+
+impl<T> RowIndexIterator for OwnedRowsIter<T>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    #[inline]
+    fn next_row_index(&self) -> Option<usize> {
+        self.iter.next_row_index()
+    }
+}
+
+impl<T> TryRowsIterator for OwnedRowsIter<T>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    type Row = T;
+
+    #[inline]
+    fn try_next(&mut self) -> Option<Result<T, RowsIterError>> {
+        self.iter.try_next()
+    }
+
+    #[inline]
+    fn try_next_into(&mut self, row: &mut T) -> Option<Result<(), RowsIterError>> {
+        self.iter.try_next_into(row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use polars::prelude::*;
+
+    use crate::*;
+
+    #[derive(Debug, PartialEq, FromDataFrameRow)]
+    struct TestRow {
+        id: i32,
+        name: String,
+    }
+
+    fn create_rows_iter() -> PolarsResult<OwnedRowsIter<TestRow>> {
+        let df = df!(
+            "id" => [1i32, 2, 3],
+            "name" => ["a", "b", "c"]
+        )?;
+
+        df.into_rows_iter::<TestRow>()
+    }
+
+    #[test]
+    fn into_rows_iter_should_outlive_the_function_creating_the_dataframe() {
+        let mut iter = create_rows_iter().unwrap();
+
+        assert_eq!(3, iter.len());
+        assert_eq!(
+            TestRow {
+                id: 3,
+                name: "c".to_string()
+            },
+            iter.next_back().unwrap().unwrap()
+        );
+        assert_eq!(Some(0), iter.next_row_index());
+
+        let ids = std::thread::spawn(move || iter.map(|row| row.unwrap().id).collect::<Vec<_>>())
+            .join()
+            .unwrap();
+        assert_eq!(vec![1, 2], ids);
+    }
+
+    #[test]
+    fn owned_rows_iter_should_share_the_dataframe() {
+        let df = Arc::new(df!("id" => [1i32], "name" => ["a"]).unwrap());
+
+        let iter = OwnedRowsIter::<TestRow>::new(df.clone(), RowsIterOptions::default()).unwrap();
+        drop(df);
+
+        assert_eq!(1, iter.count());
+    }
+}
@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+
+use polars::prelude::*;
+use rayon::iter::{
+    plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer},
+    IndexedParallelIterator, ParallelIterator,
+};
+
+use crate::{FromDataFrameRow, RowsIterOptions, SeekRowsIterator};
+
+/// Parallel row iteration over dataframes with rayon
+pub trait DataframeParRowsIterExt<'a> {
+    fn par_rows_iter<T>(&'a self) -> PolarsResult<ParRowsIter<'a, T>>
+    where
+        T: FromDataFrameRow<'a> + Send;
+
+    fn par_rows_iter_with_options<T>(&'a self, options: RowsIterOptions) -> PolarsResult<ParRowsIter<'a, T>>
+    where
+        T: FromDataFrameRow<'a> + Send;
+}
+
+impl<'a> DataframeParRowsIterExt<'a> for DataFrame {
+    /// Creates a parallel row iterator for this DataFrame, which splits the rows into ranges decoded by separate row
+    /// iterators. The column iterators seek over whole chunks, so a range does not read the values before its start.
+    ///```rust
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///use rayon::prelude::*;
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///struct MyRow<'a> {
+    ///    name: &'a str,
+    ///    score: f64,
+    ///}
+    ///
+    ///    let df = df!(
+    ///        "name" => ["a", "b", "c"],
+    ///        "score" => [1.0f64, 2.0, 3.0]
+    ///    ).unwrap();
+    ///
+    ///    let scores = df
+    ///        .par_rows_iter::<MyRow>()
+    ///        .unwrap()
+    ///        .map(|row| row.map(|row| format!("{}: {}", row.name, row.score * 2.0)))
+    ///        .collect::<PolarsResult<Vec<String>>>()
+    ///        .unwrap();
+    ///
+    ///    assert_eq!(scores, ["a: 2", "b: 4", "c: 6"]);
+    ///```
+    fn par_rows_iter<T>(&'a self) -> PolarsResult<ParRowsIter<'a, T>>
+    where
+        T: FromDataFrameRow<'a> + Send,
+    {
+        self.par_rows_iter_with_options(RowsIterOptions::default())
+    }
+
+    /// Like `par_rows_iter()`, but with a null policy or casting of the columns like
+    /// `FromDataFrameRow::from_dataframe_with_options()`
+    fn par_rows_iter_with_options<T>(&'a self, options: RowsIterOptions) -> PolarsResult<ParRowsIter<'a, T>>
+    where
+        T: FromDataFrameRow<'a> + Send,
+    {
+        T::from_dataframe_with_options(self, HashMap::new(), options).map(ParRowsIter::new)
+    }
+}
+
+/// Parallel row iterator, splitting the rows of a generated row iterator into ranges for the rayon workers
+pub struct ParRowsIter<'a, T>
+where
+    T: FromDataFrameRow<'a>,
+{
+    iter: T::Iter,
+}
+
+impl<'a, T> ParRowsIter<'a, T>
+where
+    T: FromDataFrameRow<'a> + Send,
+{
+    /// Creates a parallel row iterator over the rows left in the given row iterator, e.g. one with custom column names
+    pub fn new(iter: T::Iter) -> Self {
+        Self { iter }
+    }
+}
+
+impl<'a, T> ParallelIterator for ParRowsIter<'a, T>
+where
+    T: FromDataFrameRow<'a> + Send,
+{
+    type Item = PolarsResult<T>;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+impl<'a, T> IndexedParallelIterator for ParRowsIter<'a, T>
+where
+    T: FromDataFrameRow<'a> + Send,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        callback.callback(RowsProducer { iter: self.iter })
+    }
+}
+
+/// Splits a row iterator into two row iterators over the rows before and after an index
+struct RowsProducer<I> {
+    iter: I,
+}
+
+impl<I, T> Producer for RowsProducer<I>
+where
+    I: DoubleEndedIterator<Item = PolarsResult<T>> + ExactSizeIterator + SeekRowsIterator + Clone + Send,
+    T: Send,
+{
+    type Item = PolarsResult<T>;
+    type IntoIter = I;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let len = self.iter.len();
+        let mut left = self.iter.clone();
+        let mut right = self.iter;
+
+        // the rows are skipped without decoding, the column iterators seek when reading the first row of each range
+        left.skip_rows_back(len - index);
+        right.skip_rows(index);
+
+        (Self { iter: left }, Self { iter: right })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::{
+        df,
+        prelude::{BooleanChunked, DataFrame, PolarsResult},
+    };
+    use rayon::prelude::*;
+
+    use crate::*;
+
+    #[derive(Debug, PartialEq, FromDataFrameRow)]
+    struct TestRow<'a> {
+        id: i32,
+        name: &'a str,
+        value: Option<f64>,
+    }
+
+    fn create_dataframe() -> DataFrame {
+        let mut df = df!(
+            "id" => (0..100).collect::<Vec<i32>>(),
+            "name" => (0..100).map(|id| format!("row {id}")).collect::<Vec<_>>(),
+            "value" => (0..100).map(|id| (id % 3 != 0).then_some(id as f64)).collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let second_chunk = df.slice(0, 37);
+        df.vstack_mut(&second_chunk).unwrap();
+        df
+    }
+
+    #[test]
+    fn par_rows_iter_should_return_the_rows_in_order() {
+        let df = create_dataframe();
+
+        let expected = df
+            .rows_iter::<TestRow>()
+            .unwrap()
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+        let rows = df
+            .par_rows_iter::<TestRow>()
+            .unwrap()
+            .with_min_len(1)
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(137, rows.len());
+        assert_eq!(expected, rows);
+    }
+
+    #[test]
+    fn par_rows_iter_should_split_skipped_rows() {
+        #[derive(Debug, PartialEq, FromDataFrameRow)]
+        struct SkipRow {
+            id: i32,
+            #[column(on_null = "skip")]
+            value: f64,
+        }
+
+        let df = create_dataframe();
+
+        let ids = df
+            .par_rows_iter::<SkipRow>()
+            .unwrap()
+            .with_min_len(1)
+            .map(|row| row.unwrap().id)
+            .collect::<Vec<_>>();
+
+        let expected = (0..100).chain(0..37).filter(|id| id % 3 != 0).collect::<Vec<_>>();
+        assert_eq!(expected, ids);
+    }
+
+    #[test]
+    fn par_rows_iter_should_split_filtered_rows_of_a_row_range_in_order() {
+        #[derive(Debug, PartialEq, FromDataFrameRow)]
+        #[rows(filter = "is_selected")]
+        struct FilteredRow {
+            #[row_index]
+            index: usize,
+            id: i32,
+            #[column(on_null = "skip")]
+            value: f64,
+        }
+
+        let mut df = create_dataframe();
+        let is_selected = df
+            .column("id")
+            .unwrap()
+            .i32()
+            .unwrap()
+            .into_iter()
+            .map(|id| id.map(|id| id % 4 != 0))
+            .collect::<BooleanChunked>()
+            .with_name("is_selected".into());
+        df.with_column(is_selected).unwrap();
+
+        let options = RowsIterOptions {
+            row_range: Some((20, 120)),
+            ..Default::default()
+        };
+        let iter = df.par_rows_iter_with_options::<FilteredRow>(options).unwrap();
+        let rows = iter
+            .with_min_len(1)
+            .map(|row| row.map(|row| (row.index, row.id)))
+            .collect::<PolarsResult<Vec<_>>>()
+            .unwrap();
+
+        let expected = (20..120)
+            .map(|index| (index, (index % 100) as i32))
+            .filter(|(_, id)| id % 3 != 0 && id % 4 != 0)
+            .collect::<Vec<_>>();
+        assert_eq!(expected.len(), rows.len());
+        assert_eq!(expected, rows);
+    }
+
+    #[test]
+    fn par_rows_iter_should_return_errors_of_failing_rows() {
+        #[derive(Debug, FromDataFrameRow)]
+        #[allow(dead_code)]
+        struct MandatoryRow {
+            value: f64,
+        }
+
+        let df = create_dataframe();
+
+        let errors = df
+            .par_rows_iter::<MandatoryRow>()
+            .unwrap()
+            .with_min_len(1)
+            .filter(|row| row.is_err())
+            .count();
+
+        assert_eq!(34 + 13, errors);
+    }
+}
@@ -0,0 +1,178 @@
+use polars::prelude::*;
+
+use crate::FromDataFrameRow;
+
+/// Reads single rows of a dataframe by index, generated by the derive macro as `{RowStruct}RowGetter` from the column
+/// iterators of the row struct fields
+pub trait RowGetter {
+    type Row;
+
+    /// Reads the row at the given index, which is guaranteed to be below the dataframe height
+    fn get_row(&self, index: usize) -> PolarsResult<Self::Row>;
+}
+
+/// Random access to rows of a dataframe. Column lookups, dtype checks and the chunks of every column are resolved once
+/// on creation, afterwards every row can be read by its index.
+pub struct RowAccessor<'a, T>
+where
+    T: FromDataFrameRow<'a>,
+{
+    height: usize,
+    getter: T::Getter,
+}
+
+impl<'a, T> RowAccessor<'a, T>
+where
+    T: FromDataFrameRow<'a>,
+{
+    /// Creates an accessor from the row getter of the row struct. Used by the derive macro, prefer
+    /// `DataframeRowsIterExt::row_accessor()` to create an accessor.
+    pub fn new(height: usize, getter: T::Getter) -> Self {
+        Self { height, getter }
+    }
+
+    /// Reads the row at the given index
+    pub fn get(&self, index: usize) -> PolarsResult<T> {
+        polars_ensure!(
+            index < self.height,
+            OutOfBounds: "Row index {index} is out of bounds for dataframe height {}", self.height
+        );
+
+        self.getter.get_row(index)
+    }
+
+    /// Number of rows in the dataframe
+    pub fn len(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.height == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::prelude::*;
+
+    use crate::*;
+
+    #[derive(Debug, PartialEq, FromDataFrameRow)]
+    struct TestRow<'a> {
+        id: i32,
+        name: &'a str,
+        value: Option<f64>,
+    }
+
+    #[test]
+    fn accessor_should_read_rows_at_arbitrary_indices() {
+        let df = df!(
+            "id" => [1i32, 2, 3],
+            "name" => ["a", "b", "c"],
+            "value" => [Some(1.0f64), None, Some(3.0)]
+        )
+        .unwrap();
+
+        let accessor = df.row_accessor::<TestRow>().unwrap();
+
+        assert_eq!(3, accessor.len());
+        assert_eq!(
+            TestRow {
+                id: 3,
+                name: "c",
+                value: Some(3.0)
+            },
+            accessor.get(2).unwrap()
+        );
+        assert_eq!(
+            TestRow {
+                id: 2,
+                name: "b",
+                value: None
+            },
+            accessor.get(1).unwrap()
+        );
+        assert!(accessor.get(3).is_err());
+    }
+
+    #[test]
+    fn accessor_should_respect_chunk_boundaries() {
+        let mut df = df!(
+            "id" => [1i32, 2],
+            "name" => ["a", "b"],
+            "value" => [Some(1.0f64), None]
+        )
+        .unwrap();
+        df.vstack_mut(
+            &df!(
+                "id" => [3i32],
+                "name" => ["c"],
+                "value" => [Some(3.0f64)]
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(2, df.first_col_n_chunks());
+        assert_eq!(
+            TestRow {
+                id: 3,
+                name: "c",
+                value: Some(3.0)
+            },
+            df.row_at::<TestRow>(2).unwrap()
+        );
+    }
+
+    #[test]
+    fn accessor_should_be_sent_to_other_threads() {
+        let df = df!(
+            "id" => [1i32, 2],
+            "name" => ["a", "b"],
+            "value" => [Some(1.0f64), None]
+        )
+        .unwrap();
+
+        let accessor = df.row_accessor::<TestRow>().unwrap();
+        let name = std::thread::scope(|scope| scope.spawn(move || accessor.get(1).unwrap().name).join().unwrap());
+
+        assert_eq!("b", name);
+    }
+
+    #[test]
+    fn accessor_should_fail_on_creation_when_dtype_does_not_match() {
+        let df = df!(
+            "id" => [1i64, 2, 3],
+            "name" => ["a", "b", "c"],
+            "value" => [Some(1.0f64), None, Some(3.0)]
+        )
+        .unwrap();
+
+        assert!(df.row_accessor::<TestRow>().is_err());
+    }
+
+    #[test]
+    fn accessor_should_be_shared_between_threads() {
+        let df = df!(
+            "id" => [1i32, 2, 3],
+            "name" => ["a", "b", "c"],
+            "value" => [Some(1.0f64), None, Some(3.0)]
+        )
+        .unwrap();
+
+        let accessor = df.row_accessor::<TestRow>().unwrap();
+
+        let accessor = &accessor;
+        let ids = std::thread::scope(|scope| {
+            let handles = (0..3)
+                .map(|index| scope.spawn(move || accessor.get(2 - index).unwrap().id))
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+
+        assert_eq!([3, 2, 1], ids.as_slice());
+    }
+}
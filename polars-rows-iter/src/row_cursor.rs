@@ -0,0 +1,440 @@
+use std::sync::Arc;
+
+use polars::prelude::*;
+
+use crate::RowsIterError;
+
+/// Row iterator which knows the dataframe index of its rows, implemented by the derive macro
+pub trait RowIndexIterator: Iterator {
+    /// Dataframe index of the row returned by the next call of `next()`
+    fn next_row_index(&self) -> Option<usize>;
+}
+
+/// Row iterator which can be narrowed to a selection of dataframe rows, implemented by the derive macro
+pub trait SelectRowsIterator: Sized {
+    /// Copy of this iterator over the given dataframe row indices in ascending order, as far as they are selected by this
+    /// iterator and not yet iterated. Duplicate indices are read once. The column iterators of the copy seek to the rows
+    /// from their current position.
+    fn select_rows(&self, rows: impl IntoIterator<Item = usize>) -> Self;
+}
+
+/// Row iterator which can skip rows without decoding them, implemented by the derive macro
+pub trait SeekRowsIterator {
+    /// Skips the next `n` rows from the front, the column iterators seek to the next row read
+    fn skip_rows(&mut self, n: usize);
+
+    /// Skips the next `n` rows from the back, the column iterators seek to the next row read
+    fn skip_rows_back(&mut self, n: usize);
+}
+
+/// Tracks the rows left to iterate by a row iterator, either all rows of the dataframe or a selection of them.
+/// Used by the derive macro to keep the column iterators in sync while seeking from both ends. Clones share the row
+/// selection.
+#[derive(Clone)]
+pub struct RowCursor {
+    rows: Option<Arc<[usize]>>,
+    front: usize,
+    back: usize,
+    front_row: usize,
+    back_row: usize,
+    row_offset: usize,
+}
+
+impl RowCursor {
+    /// Cursor over all rows of a dataframe with the given height
+    pub fn new(height: usize) -> Self {
+        Self {
+            rows: None,
+            front: 0,
+            back: height,
+            front_row: 0,
+            back_row: height,
+            row_offset: 0,
+        }
+    }
+
+    /// Cursor over the given ascending row indices of a dataframe with the given height
+    pub fn with_rows(height: usize, rows: Vec<usize>) -> Self {
+        Self {
+            front: 0,
+            back: rows.len(),
+            rows: Some(rows.into()),
+            front_row: 0,
+            back_row: height,
+            row_offset: 0,
+        }
+    }
+
+    /// Cursor over all rows of a dataframe with the given height, which have no None/null value in any of the columns
+    pub fn skipping_null_rows(height: usize, columns: &[&Column]) -> Self {
+        let null_columns = columns
+            .iter()
+            .filter(|column| column.null_count() > 0)
+            .collect::<Vec<_>>();
+        if null_columns.is_empty() {
+            return Self::new(height);
+        }
+
+        let mut has_null = null_columns[0].is_null();
+        for column in &null_columns[1..] {
+            has_null = has_null | column.is_null();
+        }
+
+        let rows = has_null
+            .iter()
+            .enumerate()
+            .filter_map(|(index, has_null)| (has_null != Some(true)).then_some(index))
+            .collect();
+
+        Self::with_rows(height, rows)
+    }
+
+    /// Restricts the cursor to the rows with an index in the given range of start (inclusive) and end (exclusive) index.
+    /// Must be called before moving the cursor.
+    pub fn with_row_range(self, row_range: Option<(usize, usize)>) -> PolarsResult<Self> {
+        let Some((start, end)) = row_range else {
+            return Ok(self);
+        };
+
+        let height = self.back_row;
+        polars_ensure!(
+            start <= end && end <= height,
+            OutOfBounds: "Row range {start}..{end} is out of bounds for dataframe height {height}"
+        );
+
+        let (front, back) = match &self.rows {
+            Some(rows) => (
+                rows.partition_point(|row| *row < start),
+                rows.partition_point(|row| *row < end),
+            ),
+            None => (start, end),
+        };
+
+        Ok(Self { front, back, ..self })
+    }
+
+    /// Cursor over the given row indices, sorted and without duplicates, as far as they are selected by this cursor and
+    /// not yet moved past. Keeps the positions of the column iterators, so that it can be used with copies of already
+    /// moved ones.
+    pub fn select_rows(&self, rows: impl IntoIterator<Item = usize>) -> Self {
+        let mut rows = rows.into_iter().collect::<Vec<_>>();
+        rows.sort_unstable();
+        rows.dedup();
+
+        let selected_rows: Vec<usize> = rows
+            .into_iter()
+            .filter(|row| match &self.rows {
+                Some(selected_rows) => selected_rows[self.front..self.back].binary_search(row).is_ok(),
+                None => (self.front..self.back).contains(row),
+            })
+            .collect();
+
+        Self {
+            front: 0,
+            back: selected_rows.len(),
+            rows: Some(selected_rows.into()),
+            ..*self
+        }
+    }
+
+    /// Adds the offset to the returned row indices, while the values are still read from the start of the columns
+    pub fn with_row_offset(self, row_offset: usize) -> Self {
+        Self { row_offset, ..self }
+    }
+
+    /// Cursor over the rows of a dataframe with the given height, which have no None/null value in any of the columns
+    /// and are selected by all filter masks. Rows with a None/null mask value are not selected.
+    pub fn selecting_rows(height: usize, columns: &[&Column], filters: &[&BooleanChunked]) -> PolarsResult<Self> {
+        let Some((first_filter, filters)) = filters.split_first() else {
+            return Ok(Self::skipping_null_rows(height, columns));
+        };
+
+        for filter in std::iter::once(first_filter).chain(filters) {
+            polars_ensure!(
+                filter.len() == height,
+                ShapeMismatch: "Filter mask of length {} does not match the dataframe height {height}", filter.len()
+            );
+        }
+
+        let mut selected = first_filter.fill_null_with_values(false)?;
+        for filter in filters {
+            selected = &selected & &filter.fill_null_with_values(false)?;
+        }
+        for column in columns.iter().filter(|column| column.null_count() > 0) {
+            selected = &selected & &column.is_not_null();
+        }
+
+        let rows = selected
+            .iter()
+            .enumerate()
+            .filter_map(|(index, is_selected)| (is_selected == Some(true)).then_some(index))
+            .collect();
+
+        Ok(Self::with_rows(height, rows))
+    }
+
+    /// Number of rows left
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.back - self.front
+    }
+
+    /// Dataframe index of the first row left, including the row offset, if the column has a None/null value in every
+    /// row left. Used by the derive macro to fail early on mandatory columns, which would fail for every row.
+    pub fn all_null_row(&self, column: &Column) -> Option<usize> {
+        if self.is_empty() || column.null_count() == 0 {
+            return None;
+        }
+
+        let is_all_null = column.null_count() == column.len()
+            || match &self.rows {
+                Some(rows) => {
+                    // the rows are ascending, so the chunks of the mask are walked along with them
+                    let is_null = column.is_null();
+                    let mut chunks = is_null.downcast_iter();
+                    let (mut chunk, mut chunk_start) = (chunks.next(), 0);
+                    rows[self.front..self.back].iter().all(|row| {
+                        while let Some(array) = chunk.filter(|array| *row >= chunk_start + array.len()) {
+                            chunk_start += array.len();
+                            chunk = chunks.next();
+                        }
+                        chunk.is_some_and(|array| array.value(row - chunk_start))
+                    })
+                }
+                None => column.slice(self.front as i64, self.len()).null_count() == self.len(),
+            };
+
+        is_all_null.then(|| self.row_index(self.front) + self.row_offset)
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Dataframe index of the next row from the front
+    pub fn next_row_index(&self) -> Option<usize> {
+        (!self.is_empty()).then(|| self.row_index(self.front) + self.row_offset)
+    }
+
+    /// Moves the cursor `n + 1` rows forward, returns the dataframe index of the row and the number of column values to
+    /// skip from the front before reading the value of the row, or None if the cursor has no rows left.
+    #[inline]
+    pub fn nth(&mut self, n: usize) -> Option<(usize, usize)> {
+        if n >= self.len() {
+            self.front = self.back;
+            return None;
+        }
+
+        let row = self.row_index(self.front + n);
+        self.front += n + 1;
+
+        let skip = row - self.front_row;
+        self.front_row = row + 1;
+
+        Some((row + self.row_offset, skip))
+    }
+
+    /// Moves the cursor `n + 1` rows backward, returns the dataframe index of the row and the number of column values
+    /// to skip from the back before reading the value of the row, or None if the cursor has no rows left.
+    #[inline]
+    pub fn nth_back(&mut self, n: usize) -> Option<(usize, usize)> {
+        if n >= self.len() {
+            self.back = self.front;
+            return None;
+        }
+
+        self.back -= n + 1;
+        let row = self.row_index(self.back);
+
+        let skip = self.back_row - row - 1;
+        self.back_row = row;
+
+        Some((row + self.row_offset, skip))
+    }
+
+    /// Moves the cursor `n` rows forward without returning a row, the skipped column values are added to the skip of
+    /// the next row returned by `nth()`
+    #[inline]
+    pub fn skip(&mut self, n: usize) {
+        self.front += n.min(self.len());
+    }
+
+    /// Moves the cursor `n` rows backward without returning a row, the skipped column values are added to the skip of
+    /// the next row returned by `nth_back()`
+    #[inline]
+    pub fn skip_back(&mut self, n: usize) {
+        self.back -= n.min(self.len());
+    }
+
+    #[inline]
+    fn row_index(&self, position: usize) -> usize {
+        match &self.rows {
+            Some(rows) => rows[position],
+            None => position,
+        }
+    }
+}
+
+/// Converts a row index into the type of a `#[row_index]` field, used by the derive macro
+#[inline]
+pub fn row_index_value<T>(field: &str, row: usize) -> Result<T, RowsIterError>
+where
+    T: TryFrom<usize>,
+{
+    T::try_from(row).map_err(|_| RowsIterError::OutOfBounds {
+        column: field.to_string(),
+        row,
+        value: row.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_should_return_value_gaps_between_selected_rows() {
+        let mut cursor = RowCursor::with_rows(10, vec![1, 2, 5, 8]);
+
+        assert_eq!(4, cursor.len());
+        assert_eq!(Some(1), cursor.next_row_index());
+        assert_eq!(Some((1, 1)), cursor.nth(0));
+        assert_eq!(Some((5, 3)), cursor.nth(1));
+        assert_eq!(Some(8), cursor.next_row_index());
+        assert_eq!(Some((8, 1)), cursor.nth_back(0));
+        assert_eq!(None, cursor.nth(0));
+    }
+
+    #[test]
+    fn cursor_should_add_skipped_rows_to_the_value_gap_of_the_next_row() {
+        let mut cursor = RowCursor::with_rows(10, vec![1, 2, 5, 8, 9]);
+
+        cursor.skip(2);
+        cursor.skip_back(1);
+
+        assert_eq!(2, cursor.len());
+        assert_eq!(Some(5), cursor.next_row_index());
+        assert_eq!(Some((8, 1)), cursor.nth_back(0));
+        assert_eq!(Some((5, 5)), cursor.nth(0));
+
+        cursor.skip(1);
+        cursor.skip_back(1);
+        assert!(cursor.is_empty());
+    }
+
+    #[test]
+    fn cursor_should_skip_rows_with_null_values() {
+        let a = Column::new("a".into(), [Some(1), None, Some(3), Some(4)]);
+        let b = Column::new("b".into(), [Some(1), Some(2), Some(3), None]);
+        let c = Column::new("c".into(), [1, 2, 3, 4]);
+
+        let mut cursor = RowCursor::skipping_null_rows(4, &[&a, &b, &c]);
+
+        assert_eq!(2, cursor.len());
+        assert_eq!(Some((0, 0)), cursor.nth(0));
+        assert_eq!(Some((2, 1)), cursor.nth(0));
+        assert!(cursor.is_empty());
+        assert!(RowCursor::skipping_null_rows(4, &[&c]).rows.is_none());
+    }
+
+    #[test]
+    fn cursor_should_add_row_offset_to_returned_rows_only() {
+        let mut cursor = RowCursor::with_rows(10, vec![1, 2, 5]).with_row_offset(100);
+
+        assert_eq!(Some(101), cursor.next_row_index());
+        assert_eq!(Some((102, 2)), cursor.nth(1));
+        assert_eq!(Some((105, 4)), cursor.nth_back(0));
+    }
+
+    #[test]
+    fn cursor_should_select_rows_of_all_filters_without_null_values() {
+        let a = Column::new("a".into(), [Some(1), None, Some(3), Some(4), Some(5)]);
+        let filter = BooleanChunked::new("filter".into(), [Some(true), Some(true), None, Some(true), Some(true)]);
+        let other_filter = BooleanChunked::new("other".into(), [true, true, true, false, true]);
+
+        let mut cursor = RowCursor::selecting_rows(5, &[&a], &[&filter, &other_filter]).unwrap();
+
+        assert_eq!(2, cursor.len());
+        assert_eq!(Some((0, 0)), cursor.nth(0));
+        assert_eq!(Some((4, 3)), cursor.nth(0));
+        assert!(RowCursor::selecting_rows(4, &[&a], &[&filter]).is_err());
+    }
+
+    #[test]
+    fn cursor_should_seek_to_the_start_of_the_row_range() {
+        let mut cursor = RowCursor::new(10).with_row_range(Some((3, 6))).unwrap();
+
+        assert_eq!(3, cursor.len());
+        assert_eq!(Some((3, 3)), cursor.nth(0));
+        assert_eq!(Some((5, 4)), cursor.nth_back(0));
+
+        let mut cursor = RowCursor::with_rows(10, vec![1, 2, 5, 8])
+            .with_row_range(Some((2, 8)))
+            .unwrap();
+
+        assert_eq!(2, cursor.len());
+        assert_eq!(Some((2, 2)), cursor.nth(0));
+        assert_eq!(Some((5, 4)), cursor.nth_back(0));
+        assert!(RowCursor::new(10).with_row_range(Some((3, 11))).is_err());
+        assert!(RowCursor::new(10).with_row_range(Some((4, 3))).is_err());
+    }
+
+    #[test]
+    fn cursor_should_select_rows_within_its_own_selection() {
+        let cursor = RowCursor::with_rows(10, vec![1, 2, 5, 8])
+            .with_row_range(Some((2, 10)))
+            .unwrap();
+
+        let mut selected = cursor.select_rows([1, 5, 6, 8]);
+
+        assert_eq!(2, selected.len());
+        assert_eq!(Some((5, 5)), selected.nth(0));
+        assert_eq!(Some((8, 1)), selected.nth_back(0));
+        assert_eq!(
+            [Some(3), Some(4)],
+            [3, 4].map(|row| RowCursor::new(5).select_rows([row]).next_row_index())
+        );
+    }
+
+    #[test]
+    fn cursor_should_select_rows_from_the_positions_of_a_moved_cursor() {
+        let mut cursor = RowCursor::new(10);
+        assert_eq!(Some((2, 2)), cursor.nth(2));
+        assert_eq!(Some((8, 1)), cursor.nth_back(1));
+
+        let mut selected = cursor.select_rows([1, 4, 6, 8]);
+
+        assert_eq!(2, selected.len());
+        assert_eq!(Some((4, 1)), selected.nth(0));
+        assert_eq!(Some((6, 1)), selected.nth_back(0));
+    }
+
+    #[test]
+    fn cursor_should_select_unsorted_and_duplicate_rows_once_in_ascending_order() {
+        let mut cursor = RowCursor::new(10);
+        assert_eq!(Some((1, 1)), cursor.nth(1));
+
+        let mut selected = cursor.select_rows([6, 2, 9, 2, 6, 0]);
+
+        assert_eq!(3, selected.len());
+        assert_eq!(Some((2, 0)), selected.nth(0));
+        assert_eq!(Some((6, 3)), selected.nth(0));
+        assert_eq!(Some((9, 2)), selected.nth(0));
+        assert_eq!(None, selected.nth(0));
+    }
+
+    #[test]
+    fn all_null_row_should_check_the_selected_rows_across_chunks() {
+        let mut series = Series::new("value".into(), [Some(1i32), None, None]);
+        series
+            .append(&Series::new("value".into(), [None, Some(5i32), None]))
+            .unwrap();
+        let column = series.into_column();
+        assert_eq!(2, column.n_chunks());
+
+        assert_eq!(Some(1), RowCursor::with_rows(6, vec![1, 2, 3, 5]).all_null_row(&column));
+        assert_eq!(None, RowCursor::with_rows(6, vec![2, 3, 4]).all_null_row(&column));
+        assert_eq!(None, RowCursor::with_rows(6, vec![0]).all_null_row(&column));
+    }
+}
@@ -0,0 +1,201 @@
+use polars::prelude::*;
+
+use crate::{FromDataFrameRow, RowError, RowIndexIterator, TryRowsIterator};
+
+/// Iterator over batches of rows of a dataframe, created by `DataframeRowsIterExt::rows_batches()`. Every batch covers
+/// `batch_size` rows, except for the last one, and is returned together with the errors of its failed rows.
+pub struct RowsBatches<'a, T>
+where
+    T: FromDataFrameRow<'a>,
+{
+    iter: T::Iter,
+    batch_size: usize,
+}
+
+impl<'a, T> RowsBatches<'a, T>
+where
+    T: FromDataFrameRow<'a>,
+{
+    pub(crate) fn new(iter: T::Iter, batch_size: usize) -> PolarsResult<Self> {
+        polars_ensure!(batch_size > 0, InvalidOperation: "Batch size must be greater than 0");
+
+        Ok(Self { iter, batch_size })
+    }
+
+    /// Reads the next batch into the given vectors, which are cleared before, reusing their allocations across batches.
+    /// Returns false if no rows are left. All rows of the batch are decoded, the rows which did not fail are pushed to
+    /// `batch` and the errors of the failed rows to `errors`.
+    pub fn next_batch_into(&mut self, batch: &mut Vec<T>, errors: &mut Vec<RowError>) -> bool {
+        batch.clear();
+        errors.clear();
+
+        let len = self.batch_size.min(self.iter.len());
+        read_batch(&mut self.iter, len, batch, errors);
+
+        len > 0
+    }
+}
+
+impl<'a, T> Iterator for RowsBatches<'a, T>
+where
+    T: FromDataFrameRow<'a>,
+{
+    type Item = (Vec<T>, Vec<RowError>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.iter.len() == 0 {
+            return None;
+        }
+
+        let mut batch = Vec::new();
+        let mut errors = Vec::new();
+        self.next_batch_into(&mut batch, &mut errors);
+        Some((batch, errors))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len().div_ceil(self.batch_size);
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RowsBatches<'a, T> where T: FromDataFrameRow<'a> {}
+
+/// Decodes the next `len` rows, pushing the rows which did not fail to the batch and the errors of the others to `errors`
+pub(crate) fn read_batch<I, T>(rows: &mut I, len: usize, batch: &mut Vec<T>, errors: &mut Vec<RowError>)
+where
+    I: RowIndexIterator + TryRowsIterator<Row = T>,
+{
+    batch.reserve(len);
+
+    for _ in 0..len {
+        let Some(row_index) = rows.next_row_index() else {
+            break;
+        };
+
+        match rows.try_next() {
+            Some(Ok(row)) => batch.push(row),
+            Some(Err(error)) => errors.push(RowError { row_index, error }),
+            None => break,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::prelude::*;
+
+    use crate::*;
+
+    #[derive(Debug, PartialEq, FromDataFrameRow)]
+    struct TestRow<'a> {
+        id: i32,
+        name: &'a str,
+    }
+
+    fn create_dataframe(height: i32) -> DataFrame {
+        let names = (0..height).map(|id| format!("name {id}")).collect::<Vec<_>>();
+        df!(
+            "id" => (0..height).collect::<Vec<_>>(),
+            "name" => names
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn rows_batches_should_yield_full_batches_and_a_shorter_last_batch() {
+        let df = create_dataframe(7);
+
+        let batches = df.rows_batches::<TestRow>(3).unwrap();
+        assert_eq!(3, batches.len());
+
+        let ids = batches
+            .map(|(batch, errors)| {
+                assert!(errors.is_empty());
+                batch.iter().map(|row| row.id).collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]], ids);
+        assert_eq!(0, create_dataframe(0).rows_batches::<TestRow>(3).unwrap().count());
+        assert!(df.rows_batches::<TestRow>(0).is_err());
+    }
+
+    #[test]
+    fn next_batch_into_should_reuse_the_allocation_of_the_batch() {
+        let df = create_dataframe(5);
+        let mut batches = df.rows_batches::<TestRow>(2).unwrap();
+        let mut batch = Vec::new();
+        let mut errors = Vec::new();
+
+        assert!(batches.next_batch_into(&mut batch, &mut errors));
+        let capacity = batch.capacity();
+        assert!(batches.next_batch_into(&mut batch, &mut errors));
+        assert_eq!(capacity, batch.capacity());
+        assert_eq!(vec![2, 3], batch.iter().map(|row| row.id).collect::<Vec<_>>());
+
+        assert!(batches.next_batch_into(&mut batch, &mut errors));
+        assert_eq!(1, batch.len());
+        assert!(!batches.next_batch_into(&mut batch, &mut errors));
+        assert!(batch.is_empty());
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn rows_batches_should_keep_the_rows_of_a_batch_which_did_not_fail() {
+        #[derive(Debug, FromDataFrameRow)]
+        struct MandatoryRow {
+            #[row_index]
+            index: usize,
+            value: f64,
+        }
+
+        let df = df!("value" => [Some(1.0f64), None, None, Some(4.0), None, Some(6.0), Some(7.0)]).unwrap();
+
+        let batches = df
+            .rows_batches::<MandatoryRow>(3)
+            .unwrap()
+            .map(|(batch, errors)| {
+                (
+                    batch.iter().map(|row| row.index).collect::<Vec<_>>(),
+                    errors.iter().map(|error| error.row_index).collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![(vec![0], vec![1, 2]), (vec![3, 5], vec![4]), (vec![6], vec![])],
+            batches
+        );
+    }
+
+    #[test]
+    fn next_batch_into_should_return_the_errors_of_the_failed_rows() {
+        #[derive(Debug, FromDataFrameRow)]
+        struct MandatoryRow {
+            #[row_index]
+            index: usize,
+            value: f64,
+        }
+
+        let df = df!("value" => [Some(1.0f64), None, Some(3.0), None, Some(5.0)]).unwrap();
+        let mut batches = df.rows_batches::<MandatoryRow>(4).unwrap();
+        let mut batch = Vec::new();
+        let mut errors = Vec::new();
+
+        assert!(batches.next_batch_into(&mut batch, &mut errors));
+        assert_eq!(vec![0, 2], batch.iter().map(|row| row.index).collect::<Vec<_>>());
+        assert_eq!(
+            vec![1, 3],
+            errors.iter().map(|error| error.row_index).collect::<Vec<_>>()
+        );
+        assert!(matches!(
+            errors[0].error,
+            RowsIterError::UnexpectedNull { ref column, row: 1 } if column == "value"
+        ));
+
+        assert!(batches.next_batch_into(&mut batch, &mut errors));
+        assert_eq!(vec![4], batch.iter().map(|row| row.index).collect::<Vec<_>>());
+        assert!(errors.is_empty());
+    }
+}
@@ -0,0 +1,301 @@
+use std::fmt::{Display, Formatter};
+
+use polars::prelude::*;
+
+/// Structured error of a row iterator, pointing to the affected column and row. Converts into a `PolarsError`, which
+/// is returned by the row iterators. Use `TryRowsIterator::try_next()` to get the structured errors of failed rows.
+#[derive(Debug)]
+pub enum RowsIterError {
+    /// The column of a field is not available in the dataframe, with a similarly named column if there is one
+    MissingColumn {
+        field: String,
+        column: String,
+        suggestion: Option<String>,
+    },
+    /// The dtype of the column is not supported by the field type, with the dtypes accepted by the field type like
+    /// `IterFromColumn::accepted_dtypes()`
+    DtypeMismatch {
+        field: String,
+        column: String,
+        expected: Vec<DataType>,
+        found: DataType,
+    },
+    /// The column could not be cast to the preferred dtype of the field type, e.g. because of a lossy cast
+    CastFailed {
+        field: String,
+        column: String,
+        to: DataType,
+        cause: Box<PolarsError>,
+    },
+    /// The column of a mandatory field contains a None/null value
+    UnexpectedNull { column: String, row: usize },
+    /// The columns of mandatory fields contain None/null values, found by `DataframeRowsIterExt::validate_nulls()`
+    UnexpectedNulls { columns: Vec<NullColumn> },
+    /// The value is out of the range of the field type
+    OutOfBounds { column: String, row: usize, value: String },
+    /// The value could not be converted into the field type
+    Conversion {
+        column: String,
+        row: usize,
+        cause: PolarsError,
+    },
+    /// The dataframe does not match the row struct, contains all missing columns and dtype mismatches
+    InvalidSchema { errors: Vec<RowsIterError> },
+}
+
+/// Column of a mandatory field with None/null values, reported by `RowsIterError::UnexpectedNulls`
+#[derive(Debug, Clone, PartialEq)]
+pub struct NullColumn {
+    /// Name of the column
+    pub column: String,
+    /// Number of None/null values in the column
+    pub null_count: usize,
+    /// Index of the first row with a None/null value
+    pub first_row: usize,
+}
+
+impl RowsIterError {
+    /// Name of the affected column, if the error is related to a single column
+    pub fn column(&self) -> Option<&str> {
+        match self {
+            RowsIterError::MissingColumn { column, .. }
+            | RowsIterError::DtypeMismatch { column, .. }
+            | RowsIterError::CastFailed { column, .. }
+            | RowsIterError::UnexpectedNull { column, .. }
+            | RowsIterError::OutOfBounds { column, .. }
+            | RowsIterError::Conversion { column, .. } => Some(column),
+            RowsIterError::UnexpectedNulls { .. } | RowsIterError::InvalidSchema { .. } => None,
+        }
+    }
+
+    /// Index of the affected row, if the error is related to a single row
+    pub fn row(&self) -> Option<usize> {
+        match self {
+            RowsIterError::MissingColumn { .. }
+            | RowsIterError::DtypeMismatch { .. }
+            | RowsIterError::CastFailed { .. }
+            | RowsIterError::UnexpectedNulls { .. }
+            | RowsIterError::InvalidSchema { .. } => None,
+            RowsIterError::UnexpectedNull { row, .. }
+            | RowsIterError::OutOfBounds { row, .. }
+            | RowsIterError::Conversion { row, .. } => Some(*row),
+        }
+    }
+}
+
+impl Display for RowsIterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RowsIterError::MissingColumn {
+                field,
+                column,
+                suggestion,
+            } => {
+                write!(f, "Column '{column}' of field '{field}' not found")?;
+                match suggestion {
+                    Some(suggestion) => write!(f, ", did you mean '{suggestion}'?"),
+                    None => Ok(()),
+                }
+            }
+            RowsIterError::DtypeMismatch {
+                field,
+                column,
+                expected,
+                found,
+            } => {
+                write!(f, "Column '{column}' of field '{field}' has dtype '{found}', expected ")?;
+                write_dtypes(f, expected)
+            }
+            RowsIterError::CastFailed {
+                field,
+                column,
+                to,
+                cause,
+            } => write!(f, "Cannot cast column '{column}' of field '{field}' to '{to}': {cause}"),
+            RowsIterError::UnexpectedNull { column, row } => write!(
+                f,
+                "Found unexpected None/null value in column {column} with mandatory values at row {row}!"
+            ),
+            RowsIterError::UnexpectedNulls { columns } => {
+                write!(
+                    f,
+                    "Found unexpected None/null values in columns with mandatory values: "
+                )?;
+                for (index, column) in columns.iter().enumerate() {
+                    let separator = if index > 0 { ", " } else { "" };
+                    write!(
+                        f,
+                        "{separator}'{}' ({} null values, first at row {})",
+                        column.column, column.null_count, column.first_row
+                    )?;
+                }
+                Ok(())
+            }
+            RowsIterError::OutOfBounds { column, row, value } => {
+                write!(f, "Value {value} in column {column} at row {row} is out of bounds")
+            }
+            RowsIterError::Conversion { column, row, cause } => {
+                write!(f, "Failed to convert value in column {column} at row {row}: {cause}")
+            }
+            RowsIterError::InvalidSchema { errors } => {
+                write!(f, "Dataframe does not match the row struct:")?;
+                errors.iter().try_for_each(|error| write!(f, "\n  - {error}"))
+            }
+        }
+    }
+}
+
+impl std::error::Error for RowsIterError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RowsIterError::CastFailed { cause, .. } => Some(cause.as_ref()),
+            RowsIterError::Conversion { cause, .. } => Some(cause),
+            _ => None,
+        }
+    }
+}
+
+/// Writes the dtypes as `'a'`, `'a' or 'b'` or `'a', 'b' or 'c'`. Parametrized dtypes like `Datetime(..)` are written
+/// without their placeholder parameters, as they stand for the dtype with any parameters.
+fn write_dtypes(f: &mut Formatter<'_>, dtypes: &[DataType]) -> std::fmt::Result {
+    for (index, dtype) in dtypes.iter().enumerate() {
+        let separator = match index {
+            0 => "",
+            index if index + 1 == dtypes.len() => " or ",
+            _ => ", ",
+        };
+        match dtype {
+            DataType::Datetime(_, _) => write!(f, "{separator}'datetime'")?,
+            DataType::Duration(_) => write!(f, "{separator}'duration'")?,
+            DataType::List(_) => write!(f, "{separator}'list'")?,
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(_, _) => write!(f, "{separator}'cat'")?,
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(_, _) => write!(f, "{separator}'enum'")?,
+            dtype => write!(f, "{separator}'{dtype}'")?,
+        }
+    }
+    Ok(())
+}
+
+impl From<RowsIterError> for PolarsError {
+    fn from(error: RowsIterError) -> Self {
+        let message = error.to_string();
+        match error {
+            RowsIterError::MissingColumn { .. } => PolarsError::ColumnNotFound(message.into()),
+            RowsIterError::DtypeMismatch { .. }
+            | RowsIterError::CastFailed { .. }
+            | RowsIterError::UnexpectedNull { .. }
+            | RowsIterError::UnexpectedNulls { .. } => PolarsError::SchemaMismatch(message.into()),
+            RowsIterError::OutOfBounds { .. } => PolarsError::OutOfBounds(message.into()),
+            RowsIterError::InvalidSchema { errors } => {
+                match errors
+                    .iter()
+                    .any(|error| matches!(error, RowsIterError::MissingColumn { .. }))
+                {
+                    true => PolarsError::ColumnNotFound(message.into()),
+                    false => PolarsError::SchemaMismatch(message.into()),
+                }
+            }
+            RowsIterError::Conversion { column, row, cause } => {
+                cause.context(format!("Failed to convert value in column {column} at row {row}").into())
+            }
+        }
+    }
+}
+
+/// Row iterator which returns structured errors for failed rows, implemented by the derive macro
+pub trait TryRowsIterator: Iterator {
+    type Row;
+
+    /// Like `next()`, but returns the structured error if the row creation fails
+    fn try_next(&mut self) -> Option<Result<Self::Row, RowsIterError>>;
+
+    /// Like `try_next()`, but overwrites the given row in place instead of creating a new one, so that owned fields like
+    /// `String` reuse their allocation. The row is left partially updated if the row creation fails.
+    fn try_next_into(&mut self, row: &mut Self::Row) -> Option<Result<(), RowsIterError>> {
+        Some(self.try_next()?.map(|next_row| *row = next_row))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::prelude::*;
+
+    use crate::*;
+
+    #[derive(Debug, FromDataFrameRow)]
+    struct TestRow {
+        id: i32,
+        value: Option<f64>,
+    }
+
+    #[test]
+    fn try_next_should_return_unexpected_null_error_with_row_and_column() {
+        let df = df!(
+            "id" => [Some(1i32), None],
+            "value" => [Some(1.0f64), None]
+        )
+        .unwrap();
+
+        let mut iter = df.rows_iter::<TestRow>().unwrap();
+        let row = iter.try_next().unwrap().unwrap();
+        assert_eq!((1, Some(1.0)), (row.id, row.value));
+
+        let error = iter.try_next().unwrap().unwrap_err();
+        assert!(matches!(error, RowsIterError::UnexpectedNull { row: 1, .. }));
+        assert_eq!(Some("id"), error.column());
+        assert!(matches!(PolarsError::from(error), PolarsError::SchemaMismatch(_)));
+    }
+
+    #[test]
+    fn from_dataframe_should_return_missing_column_and_dtype_mismatch_errors() {
+        let df = df!("value" => [1.0f64]).unwrap();
+        let error = df.rows_iter::<TestRow>().err().unwrap();
+        assert!(matches!(error, PolarsError::ColumnNotFound(_)));
+
+        let df = df!("id" => [1i64], "value" => [1.0f64]).unwrap();
+        let error = df.rows_iter::<TestRow>().err().unwrap();
+        assert_eq!(
+            "Dataframe does not match the row struct:\n  - Column 'id' of field 'id' has dtype 'i64', expected 'i32' or 'date'",
+            error.to_string()
+        );
+
+        match TestRow::validate_schema(df.schema()) {
+            Err(RowsIterError::InvalidSchema { errors }) => match &errors[..] {
+                [RowsIterError::DtypeMismatch { expected, found, .. }] => {
+                    assert_eq!(&vec![DataType::Int32, DataType::Date], expected);
+                    assert_eq!(&DataType::Int64, found);
+                }
+                errors => panic!("expected dtype mismatch, got {errors:?}"),
+            },
+            result => panic!("expected schema error, got {result:?}"),
+        }
+    }
+
+    #[test]
+    fn source_should_return_the_cause_of_conversion_and_cast_errors() {
+        use std::error::Error;
+
+        let error = RowsIterError::Conversion {
+            column: "id".to_string(),
+            row: 1,
+            cause: polars_err!(ComputeError: "invalid value"),
+        };
+        assert_eq!("invalid value", error.source().unwrap().to_string());
+
+        let error = RowsIterError::CastFailed {
+            column: "id".to_string(),
+            field: "id".to_string(),
+            to: DataType::Int32,
+            cause: Box::new(polars_err!(ComputeError: "lossy cast")),
+        };
+        assert_eq!("lossy cast", error.source().unwrap().to_string());
+        assert!(RowsIterError::UnexpectedNull {
+            column: "id".to_string(),
+            row: 0
+        }
+        .source()
+        .is_none());
+    }
+}
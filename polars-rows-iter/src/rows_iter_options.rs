@@ -0,0 +1,17 @@
+use crate::NullPolicy;
+
+/// Options of a row iterator, used by `FromDataFrameRow::from_dataframe_with_options()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RowsIterOptions {
+    /// Null policy for all mandatory fields without an own `on_null` policy
+    pub null_policy: NullPolicy,
+    /// Casts columns with a dtype not supported by the field type to the preferred dtype of the field type, like
+    /// `#[rows(cast)]` on the row struct
+    pub cast: bool,
+    /// Added to the row indices of the dataframe, e.g. the offset of a sliced dataframe within the original one. Used
+    /// for `#[row_index]` fields, `RowIndexIterator::next_row_index()` and the rows of errors.
+    pub row_offset: usize,
+    /// Start (inclusive) and end (exclusive) index of the dataframe rows to iterate, the column iterators seek to the
+    /// start without reading the values before
+    pub row_range: Option<(usize, usize)>,
+}
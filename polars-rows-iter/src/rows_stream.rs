@@ -0,0 +1,317 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+use polars::prelude::*;
+
+use crate::{read_batch, FromDataFrameRow, OwnedRowsIter, RowError, RowsIterOptions};
+
+/// Async row streams over dataframes, for rows sent from async tasks
+pub trait DataframeRowsStreamExt {
+    fn rows_stream<T>(self, batch_size: usize) -> PolarsResult<RowsStream<T>>
+    where
+        T: for<'a> FromDataFrameRow<'a> + 'static;
+}
+
+impl DataframeRowsStreamExt for DataFrame {
+    /// Creates a stream of batches of rows owning this DataFrame. Every batch is a vector of `batch_size` rows, except
+    /// for the last one. The stream yields to the executor after every batch, so that decoding a large dataframe does
+    /// not block other tasks of the same thread. Like with `RowsBatches`, every batch is returned together with the errors
+    /// of its failed rows.
+    ///```rust
+    ///use futures::{executor::block_on, StreamExt};
+    ///use polars::prelude::*;
+    ///use polars_rows_iter::*;
+    ///
+    ///#[derive(Debug, FromDataFrameRow)]
+    ///struct MyRow {
+    ///    id: i32,
+    ///    name: String,
+    ///}
+    ///
+    ///    let df = df!(
+    ///        "id" => [1i32, 2, 3],
+    ///        "name" => ["a", "b", "c"]
+    ///    ).unwrap();
+    ///
+    ///    let stream = df.rows_stream::<MyRow>(2).unwrap();
+    ///    let batches = block_on(stream.collect::<Vec<_>>())
+    ///        .into_iter()
+    ///        .collect::<PolarsResult<Vec<_>>>()
+    ///        .unwrap();
+    ///
+    ///    assert_eq!(batches.iter().map(|(batch, _errors)| batch.len()).collect::<Vec<_>>(), [2, 1]);
+    ///```
+    fn rows_stream<T>(self, batch_size: usize) -> PolarsResult<RowsStream<T>>
+    where
+        T: for<'a> FromDataFrameRow<'a> + 'static,
+    {
+        polars_ensure!(batch_size > 0, InvalidOperation: "Batch size must be greater than 0");
+
+        Ok(RowsStream {
+            batch_size,
+            yield_point: YieldPoint::default(),
+            rows: OwnedRowsIter::new(self, RowsIterOptions::default())?,
+        })
+    }
+}
+
+/// Async row streams over a stream of dataframes, e.g. received from a network source
+pub trait DataframeStreamRowsExt: Stream<Item = PolarsResult<DataFrame>> + Sized {
+    fn rows_stream<T>(self, batch_size: usize) -> PolarsResult<BatchesRowsStream<Self, T>>
+    where
+        T: for<'a> FromDataFrameRow<'a> + 'static;
+}
+
+impl<S> DataframeStreamRowsExt for S
+where
+    S: Stream<Item = PolarsResult<DataFrame>>,
+{
+    /// Creates a stream of batches of the rows of all dataframes returned by this stream. Every dataframe is split into
+    /// batches of `batch_size` rows like with `DataframeRowsStreamExt::rows_stream()`, batches do not span dataframes.
+    /// Errors of the source stream and schema errors of a dataframe are returned as single items, after which the
+    /// stream continues with the next dataframe. The source stream is not polled again once it has ended.
+    fn rows_stream<T>(self, batch_size: usize) -> PolarsResult<BatchesRowsStream<Self, T>>
+    where
+        T: for<'a> FromDataFrameRow<'a> + 'static,
+    {
+        polars_ensure!(batch_size > 0, InvalidOperation: "Batch size must be greater than 0");
+
+        Ok(BatchesRowsStream {
+            batch_size,
+            yield_point: YieldPoint::default(),
+            dataframes: Some(Box::pin(self)),
+            rows: None,
+        })
+    }
+}
+
+/// Stream of batches of the rows of an owned dataframe, created by `DataframeRowsStreamExt::rows_stream()`
+pub struct RowsStream<T>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    batch_size: usize,
+    yield_point: YieldPoint,
+    rows: OwnedRowsIter<T>,
+}
+
+// the stream is never pinned structurally, the rows iterator is only accessed by `&mut`
+impl<T> Unpin for RowsStream<T> where T: for<'a> FromDataFrameRow<'a> + 'static {}
+
+impl<T> Stream for RowsStream<T>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    type Item = PolarsResult<(Vec<T>, Vec<RowError>)>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.rows.len() == 0 {
+            return Poll::Ready(None);
+        }
+
+        this.yield_point
+            .poll_batch(cx)
+            .map(|_| Some(next_batch(&mut this.rows, this.batch_size)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rows.len().div_ceil(self.batch_size);
+        (len, Some(len))
+    }
+}
+
+/// Stream of batches of the rows of a stream of dataframes, created by `DataframeStreamRowsExt::rows_stream()`
+pub struct BatchesRowsStream<S, T>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    batch_size: usize,
+    yield_point: YieldPoint,
+    // None once the source stream has ended, so that it is not polled again
+    dataframes: Option<Pin<Box<S>>>,
+    rows: Option<OwnedRowsIter<T>>,
+}
+
+// the source stream is pinned on the heap, the rows iterator is only accessed by `&mut`
+impl<S, T> Unpin for BatchesRowsStream<S, T> where T: for<'a> FromDataFrameRow<'a> + 'static {}
+
+impl<S, T> Stream for BatchesRowsStream<S, T>
+where
+    S: Stream<Item = PolarsResult<DataFrame>>,
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    type Item = PolarsResult<(Vec<T>, Vec<RowError>)>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(rows) = this.rows.as_mut().filter(|rows| rows.len() > 0) {
+                return this
+                    .yield_point
+                    .poll_batch(cx)
+                    .map(|_| Some(next_batch(rows, this.batch_size)));
+            }
+
+            let Some(dataframes) = this.dataframes.as_mut() else {
+                return Poll::Ready(None);
+            };
+
+            this.rows = match dataframes.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(dataframe))) => match OwnedRowsIter::new(dataframe, RowsIterOptions::default()) {
+                    Ok(rows) => Some(rows),
+                    Err(error) => return Poll::Ready(Some(Err(error))),
+                },
+                Poll::Ready(Some(Err(error))) => return Poll::Ready(Some(Err(error))),
+                Poll::Ready(None) => {
+                    this.dataframes = None;
+                    return Poll::Ready(None);
+                }
+                Poll::Pending => return Poll::Pending,
+            };
+        }
+    }
+}
+
+fn next_batch<T>(rows: &mut OwnedRowsIter<T>, batch_size: usize) -> PolarsResult<(Vec<T>, Vec<RowError>)>
+where
+    T: for<'a> FromDataFrameRow<'a> + 'static,
+{
+    let mut batch = Vec::new();
+    let mut errors = Vec::new();
+    read_batch(rows, batch_size.min(rows.len()), &mut batch, &mut errors);
+    Ok((batch, errors))
+}
+
+/// Yields to the executor before every batch of a stream but the first one
+#[derive(Default)]
+struct YieldPoint {
+    should_yield: bool,
+}
+
+impl YieldPoint {
+    /// Returns `Pending` every other call, after waking the task to be polled again
+    fn poll_batch(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        if self.should_yield {
+            self.should_yield = false;
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        self.should_yield = true;
+        Poll::Ready(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::task::{Context, Poll};
+
+    use futures::{executor::block_on, stream, task::noop_waker_ref, Stream, StreamExt};
+    use polars::prelude::*;
+
+    use crate::*;
+
+    #[derive(Debug, PartialEq, FromDataFrameRow)]
+    struct TestRow {
+        id: i32,
+        name: String,
+    }
+
+    fn create_dataframe(ids: std::ops::Range<i32>) -> DataFrame {
+        df!(
+            "id" => ids.clone().collect::<Vec<_>>(),
+            "name" => ids.map(|id| format!("name {id}")).collect::<Vec<_>>()
+        )
+        .unwrap()
+    }
+
+    fn poll_batch_ids<S>(stream: &mut S) -> Vec<Option<Vec<i32>>>
+    where
+        S: Stream<Item = PolarsResult<(Vec<TestRow>, Vec<RowError>)>> + Unpin,
+    {
+        let mut cx = Context::from_waker(noop_waker_ref());
+
+        std::iter::from_fn(|| match stream.poll_next_unpin(&mut cx) {
+            Poll::Ready(Some(batch)) => Some(Some(batch.unwrap().0.iter().map(|row| row.id).collect())),
+            Poll::Ready(None) => None,
+            Poll::Pending => Some(None),
+        })
+        .collect()
+    }
+
+    #[test]
+    fn rows_stream_should_yield_batches_and_to_the_executor_between_them() {
+        let mut stream = create_dataframe(0..5).rows_stream::<TestRow>(2).unwrap();
+        assert_eq!((3, Some(3)), stream.size_hint());
+
+        assert_eq!(
+            vec![Some(vec![0, 1]), None, Some(vec![2, 3]), None, Some(vec![4])],
+            poll_batch_ids(&mut stream)
+        );
+    }
+
+    #[test]
+    fn rows_stream_should_fail_on_creation_for_invalid_schema_or_batch_size() {
+        let df = create_dataframe(0..5);
+
+        assert!(df.clone().drop("name").unwrap().rows_stream::<TestRow>(2).is_err());
+        assert!(df.rows_stream::<TestRow>(0).is_err());
+    }
+
+    #[test]
+    fn batches_rows_stream_should_split_every_dataframe_into_batches() {
+        let dataframes = stream::iter([Ok(create_dataframe(0..3)), Ok(create_dataframe(3..5))]);
+        let mut stream = dataframes.rows_stream::<TestRow>(2).unwrap();
+
+        assert_eq!(
+            vec![Some(vec![0, 1]), None, Some(vec![2]), None, Some(vec![3, 4])],
+            poll_batch_ids(&mut stream)
+        );
+    }
+
+    #[test]
+    fn batches_rows_stream_should_yield_batches_of_all_dataframes_and_errors() {
+        let dataframes = stream::iter([
+            Ok(create_dataframe(0..3)),
+            Ok(create_dataframe(3..3)),
+            Err(polars_err!(ComputeError: "connection lost")),
+            Ok(create_dataframe(3..5).drop("name").unwrap()),
+            Ok(create_dataframe(5..7)),
+        ]);
+
+        let batches = block_on(dataframes.rows_stream::<TestRow>(2).unwrap().collect::<Vec<_>>());
+
+        assert_eq!(5, batches.len());
+        assert_eq!(
+            vec![vec![0, 1], vec![2], vec![5, 6]],
+            batches
+                .iter()
+                .filter_map(|batch| batch.as_ref().ok())
+                .map(|(batch, _)| batch.iter().map(|row| row.id).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        );
+        assert!(matches!(batches[2], Err(PolarsError::ComputeError(_))));
+        assert!(matches!(batches[3], Err(PolarsError::ColumnNotFound(_))));
+    }
+
+    #[test]
+    fn batches_rows_stream_should_not_poll_the_source_stream_after_it_ended() {
+        let mut dataframes = vec![create_dataframe(0..2)];
+        let mut has_ended = false;
+        let source = stream::poll_fn(move |_| {
+            assert!(!has_ended, "source stream polled after it ended");
+            let dataframe = dataframes.pop();
+            has_ended = dataframe.is_none();
+            Poll::Ready(dataframe.map(Ok))
+        });
+        let mut stream = source.rows_stream::<TestRow>(2).unwrap();
+
+        assert_eq!(vec![Some(vec![0, 1])], poll_batch_ids(&mut stream));
+        assert!(block_on(stream.next()).is_none());
+        assert!(block_on(stream.next()).is_none());
+    }
+}
@@ -0,0 +1,475 @@
+use polars::prelude::*;
+
+use crate::{IterColumn, IterFromColumn, RowsIterError};
+
+/// Field name of the boolean column of `#[rows(filter = "...")]` in schema errors, expected and resolved columns
+pub const FILTER_FIELD: &str = "#[rows(filter)]";
+
+/// Resolves the columns of a row struct, collecting all missing columns and dtype mismatches instead of failing on the
+/// first one. Used by the derive macro on iterator creation.
+#[doc(hidden)]
+pub struct SchemaResolver<'a> {
+    dataframe: &'a DataFrame,
+    cast: bool,
+    errors: Vec<RowsIterError>,
+}
+
+impl<'a> SchemaResolver<'a> {
+    /// Creates a resolver, which casts columns with unsupported dtypes to the preferred dtype of the field if `cast` is
+    /// set
+    pub fn new(dataframe: &'a DataFrame, cast: bool) -> Self {
+        Self {
+            dataframe,
+            cast,
+            errors: vec![],
+        }
+    }
+
+    /// Looks up the column of a field and creates its column iterator, returns None and records the error on failure.
+    /// Cast columns are read by a column iterator owning the chunks of the cast column.
+    pub fn resolve<T>(&mut self, field: &str, column_name: &str) -> Option<(IterColumn<'a>, T::Iter)>
+    where
+        T: IterFromColumn<'a>,
+    {
+        let column = self.column(field, column_name)?;
+
+        if self.cast && !T::accepts_dtype(column.dtype()) {
+            if let Some(dtype) = cast_dtype::<T>(column.dtype()) {
+                return self.cast_column::<T>(field, column, dtype);
+            }
+        }
+
+        match T::create_iter(column) {
+            Ok(iter) => Some((IterColumn::new(column), iter)),
+            Err(_) => self.dtype_mismatch::<T>(field, column),
+        }
+    }
+
+    /// Looks up the boolean column of `#[rows(filter = "...")]`, returns None and records the error on failure
+    pub fn resolve_filter(&mut self, column_name: &str) -> Option<&'a BooleanChunked> {
+        let column = self.column(FILTER_FIELD, column_name)?;
+
+        match column.bool() {
+            Ok(filter) => Some(filter),
+            Err(_) => {
+                self.errors.push(RowsIterError::DtypeMismatch {
+                    field: FILTER_FIELD.to_string(),
+                    column: column_name.to_string(),
+                    expected: vec![DataType::Boolean],
+                    found: column.dtype().clone(),
+                });
+                None
+            }
+        }
+    }
+
+    /// Returns all errors recorded while resolving the fields as a single `RowsIterError::InvalidSchema`
+    pub fn finish(self) -> Result<(), RowsIterError> {
+        match self.errors.is_empty() {
+            true => Ok(()),
+            false => Err(RowsIterError::InvalidSchema { errors: self.errors }),
+        }
+    }
+
+    fn column(&mut self, field: &str, column_name: &str) -> Option<&'a Column> {
+        let column = self.dataframe.column(column_name).ok();
+        if column.is_none() {
+            self.errors.push(RowsIterError::MissingColumn {
+                field: field.to_string(),
+                column: column_name.to_string(),
+                suggestion: find_similar_column_name(self.dataframe.get_column_names_str(), column_name),
+            });
+        }
+        column
+    }
+
+    fn cast_column<T>(&mut self, field: &str, column: &'a Column, dtype: DataType) -> Option<(IterColumn<'a>, T::Iter)>
+    where
+        T: IterFromColumn<'a>,
+    {
+        match column.strict_cast(&dtype) {
+            Ok(cast_column) => match T::create_cast_iter(&cast_column) {
+                Some(iter) => Some((IterColumn::cast(column, dtype), iter)),
+                None => self.dtype_mismatch::<T>(field, column),
+            },
+            Err(cause) => {
+                self.errors.push(RowsIterError::CastFailed {
+                    field: field.to_string(),
+                    column: column.name().to_string(),
+                    to: dtype,
+                    cause: Box::new(cause),
+                });
+                None
+            }
+        }
+    }
+
+    fn dtype_mismatch<T>(&mut self, field: &str, column: &Column) -> Option<(IterColumn<'a>, T::Iter)>
+    where
+        T: IterFromColumn<'a>,
+    {
+        self.errors.push(RowsIterError::DtypeMismatch {
+            field: field.to_string(),
+            column: column.name().to_string(),
+            expected: T::accepted_dtypes(),
+            found: column.dtype().clone(),
+        });
+        None
+    }
+}
+
+/// Columns are only cast to numeric and boolean dtypes, and only for field types which can iterate the cast column with
+/// `IterFromColumn::create_cast_iter()`. Strings are parsed into numbers by the cast.
+fn is_castable<'a, T>(from: &DataType, to: &DataType) -> bool
+where
+    T: IterFromColumn<'a>,
+{
+    let is_castable_dtype = |dtype: &DataType| dtype.is_primitive_numeric() || dtype.is_bool();
+    is_castable_dtype(to)
+        && (is_castable_dtype(from) || (from.is_string() && to.is_primitive_numeric()))
+        && T::create_cast_iter(&Column::new_empty(PlSmallStr::EMPTY, to)).is_some()
+}
+
+/// Dtype a column of the given dtype is cast to for the field type, or None if it is not castable
+fn cast_dtype<'a, T>(from: &DataType) -> Option<DataType>
+where
+    T: IterFromColumn<'a>,
+{
+    T::accepted_dtypes()
+        .into_iter()
+        .next()
+        .filter(|to| is_castable::<T>(from, to))
+}
+
+/// Column of a row struct field resolved against a schema by `FromDataFrameRow::validate_schema()`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedColumn {
+    /// Name of the struct field, or `FILTER_FIELD` for the filter column of `#[rows(filter = "...")]`
+    pub field: &'static str,
+    /// Name of the column in the schema
+    pub column: String,
+    /// Position of the column in the schema
+    pub index: usize,
+    /// Dtype of the column in the schema
+    pub dtype: DataType,
+}
+
+/// Columns of all fields of a row struct, resolved against a schema by `FromDataFrameRow::validate_schema()`
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ResolvedColumns(Vec<ResolvedColumn>);
+
+impl ResolvedColumns {
+    /// Resolved columns in field order
+    pub fn iter(&self) -> impl Iterator<Item = &ResolvedColumn> {
+        self.0.iter()
+    }
+
+    /// Resolved column of the given struct field
+    pub fn get(&self, field: &str) -> Option<&ResolvedColumn> {
+        self.0.iter().find(|column| column.field == field)
+    }
+
+    /// Names of the resolved columns in field order, e.g. to select only the required columns of a `LazyFrame`
+    pub fn column_names(&self) -> Vec<&str> {
+        self.0.iter().map(|column| column.column.as_str()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// Resolves the columns of a row struct against a schema only, with the same column lookups and dtype checks as
+/// `SchemaResolver`, but without any column data. Used by the derive macro to validate schemas.
+pub struct SchemaValidator<'s> {
+    schema: &'s Schema,
+    cast: bool,
+    columns: Vec<ResolvedColumn>,
+    errors: Vec<RowsIterError>,
+}
+
+impl<'s> SchemaValidator<'s> {
+    /// Creates a validator, which accepts dtypes castable to the preferred dtype of the field if `cast` is set
+    pub fn new(schema: &'s Schema, cast: bool) -> Self {
+        Self {
+            schema,
+            cast,
+            columns: vec![],
+            errors: vec![],
+        }
+    }
+
+    /// Looks up the column of a field in the schema and checks its dtype, records the error on failure
+    pub fn resolve<'a, T>(&mut self, field: &'static str, column_name: &str)
+    where
+        T: IterFromColumn<'a>,
+    {
+        let Some((index, dtype)) = self.column(field, column_name) else {
+            return;
+        };
+
+        match T::accepts_dtype(dtype) || (self.cast && cast_dtype::<T>(dtype).is_some()) {
+            true => self.columns.push(ResolvedColumn {
+                field,
+                column: column_name.to_string(),
+                index,
+                dtype: dtype.clone(),
+            }),
+            false => self.errors.push(RowsIterError::DtypeMismatch {
+                field: field.to_string(),
+                column: column_name.to_string(),
+                expected: T::accepted_dtypes(),
+                found: dtype.clone(),
+            }),
+        }
+    }
+
+    /// Looks up the boolean column of `#[rows(filter = "...")]` in the schema, records the error on failure
+    pub fn resolve_filter(&mut self, column_name: &str) {
+        let Some((index, dtype)) = self.column(FILTER_FIELD, column_name) else {
+            return;
+        };
+
+        match dtype.is_bool() {
+            true => self.columns.push(ResolvedColumn {
+                field: FILTER_FIELD,
+                column: column_name.to_string(),
+                index,
+                dtype: dtype.clone(),
+            }),
+            false => self.errors.push(RowsIterError::DtypeMismatch {
+                field: FILTER_FIELD.to_string(),
+                column: column_name.to_string(),
+                expected: vec![DataType::Boolean],
+                found: dtype.clone(),
+            }),
+        }
+    }
+
+    /// Returns the resolved columns, or all errors recorded while resolving the fields as a single
+    /// `RowsIterError::InvalidSchema`
+    pub fn finish(self) -> Result<ResolvedColumns, RowsIterError> {
+        match self.errors.is_empty() {
+            true => Ok(ResolvedColumns(self.columns)),
+            false => Err(RowsIterError::InvalidSchema { errors: self.errors }),
+        }
+    }
+
+    fn column(&mut self, field: &str, column_name: &str) -> Option<(usize, &'s DataType)> {
+        let column = self
+            .schema
+            .get_full(column_name)
+            .map(|(index, _, dtype)| (index, dtype));
+        if column.is_none() {
+            self.errors.push(RowsIterError::MissingColumn {
+                field: field.to_string(),
+                column: column_name.to_string(),
+                suggestion: find_similar_column_name(self.schema.iter_names().map(|name| name.as_str()), column_name),
+            });
+        }
+        column
+    }
+}
+
+/// Finds the column whose name is closest to the given name, if it is close enough to be a misspelling
+fn find_similar_column_name<'n>(names: impl IntoIterator<Item = &'n str>, column_name: &str) -> Option<String> {
+    let max_distance = (column_name.chars().count() / 3).max(1);
+
+    names
+        .into_iter()
+        .map(|name| {
+            let distance = match name.eq_ignore_ascii_case(column_name) {
+                true => 0,
+                false => edit_distance(name, column_name),
+            };
+            (distance, name)
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, name)| name.to_string())
+}
+
+/// Edit distance counting insertions, deletions, substitutions and transpositions of adjacent characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    (0..=a.len()).for_each(|i| distances[i][0] = i);
+    (0..=b.len()).for_each(|j| distances[0][j] = j);
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j - 1] + cost)
+                .min(distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::prelude::*;
+
+    use super::*;
+    use crate::*;
+
+    #[derive(Debug, FromDataFrameRow)]
+    #[allow(dead_code)]
+    struct TestRow<'a> {
+        id: i32,
+        name: &'a str,
+        #[column("value")]
+        amount: f64,
+        timestamp: Option<i64>,
+    }
+
+    #[test]
+    fn edit_distance_should_count_edits_and_transpositions() {
+        assert_eq!(0, edit_distance("name", "name"));
+        assert_eq!(1, edit_distance("name", "nme"));
+        assert_eq!(1, edit_distance("value", "vaule"));
+        assert_eq!(2, edit_distance("value", "vlaeu"));
+        assert_eq!(3, edit_distance("", "abc"));
+    }
+
+    #[test]
+    fn from_dataframe_should_report_all_schema_errors_with_suggestions() {
+        let df = df!(
+            "id" => [1i64],
+            "Name" => ["a"],
+            "valeu" => [1.0f64],
+            "timestamp" => ["not a timestamp"]
+        )
+        .unwrap();
+
+        let error = df.rows_iter::<TestRow>().err().unwrap();
+        assert!(matches!(error, PolarsError::ColumnNotFound(_)));
+        assert_eq!(
+            "not found: Dataframe does not match the row struct:\n  \
+             - Column 'id' of field 'id' has dtype 'i64', expected 'i32' or 'date'\n  \
+             - Column 'name' of field 'name' not found, did you mean 'Name'?\n  \
+             - Column 'value' of field 'amount' not found, did you mean 'valeu'?\n  \
+             - Column 'timestamp' of field 'timestamp' has dtype 'str', expected 'i64', 'time', 'datetime' or 'duration'",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn validate_schema_should_resolve_columns_without_data() {
+        let schema = Schema::from_iter([
+            Field::new("value".into(), DataType::Float64),
+            Field::new("id".into(), DataType::Int32),
+            Field::new("timestamp".into(), DataType::Datetime(TimeUnit::Milliseconds, None)),
+            Field::new("name".into(), DataType::String),
+        ]);
+
+        let columns = TestRow::validate_schema(&schema).unwrap();
+
+        assert_eq!(vec!["id", "name", "value", "timestamp"], columns.column_names());
+        assert_eq!(
+            &ResolvedColumn {
+                field: "amount",
+                column: "value".to_string(),
+                index: 0,
+                dtype: DataType::Float64
+            },
+            columns.get("amount").unwrap()
+        );
+    }
+
+    #[test]
+    fn validate_schema_should_report_the_same_errors_as_from_dataframe() {
+        let df = df!(
+            "id" => [1i64],
+            "Name" => ["a"],
+            "valeu" => [1.0f64],
+            "timestamp" => ["not a timestamp"]
+        )
+        .unwrap();
+
+        let schema_error = TestRow::validate_schema(df.schema()).unwrap_err();
+        let dataframe_error = df.rows_iter::<TestRow>().err().unwrap();
+
+        assert_eq!(format!("not found: {schema_error}"), dataframe_error.to_string());
+    }
+
+    #[derive(Debug, FromDataFrameRow)]
+    #[rows(filter = "is_active")]
+    #[allow(dead_code)]
+    struct FilteredRow {
+        id: i32,
+    }
+
+    #[test]
+    fn from_dataframe_should_report_filter_column_errors_with_the_field_errors() {
+        let df = df!("id" => [1i64], "is_activ" => [true]).unwrap();
+
+        let error = df.rows_iter::<FilteredRow>().err().unwrap();
+        assert!(matches!(error, PolarsError::ColumnNotFound(_)));
+        assert_eq!(
+            "not found: Dataframe does not match the row struct:\n  \
+             - Column 'id' of field 'id' has dtype 'i64', expected 'i32' or 'date'\n  \
+             - Column 'is_active' of field '#[rows(filter)]' not found, did you mean 'is_activ'?",
+            error.to_string()
+        );
+
+        let df = df!("id" => [1i32], "is_active" => [1i32]).unwrap();
+
+        let error = df.rows_iter::<FilteredRow>().err().unwrap();
+        assert!(matches!(error, PolarsError::SchemaMismatch(_)));
+        assert!(error
+            .to_string()
+            .ends_with("Column 'is_active' of field '#[rows(filter)]' has dtype 'i32', expected 'bool'"));
+        assert!(FilteredRow::validate_schema(df.schema()).is_err());
+    }
+
+    #[test]
+    fn validate_schema_should_resolve_the_filter_column() {
+        let schema = Schema::from_iter([
+            Field::new("is_active".into(), DataType::Boolean),
+            Field::new("id".into(), DataType::Int32),
+        ]);
+
+        let columns = FilteredRow::validate_schema(&schema).unwrap();
+
+        assert_eq!(vec!["id", "is_active"], columns.column_names());
+        assert_eq!(0, columns.get(FILTER_FIELD).unwrap().index);
+        assert_eq!(
+            vec![("id", false), (FILTER_FIELD, true)],
+            FilteredRow::expected_schema()
+                .iter()
+                .map(|column| (column.field, column.nullable))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn resolver_should_not_suggest_unrelated_columns() {
+        let df = df!("completely_different" => [1i32]).unwrap();
+
+        let mut resolver = SchemaResolver::new(&df, false);
+        assert!(resolver.resolve::<i32>("id", "id").is_none());
+
+        match resolver.finish() {
+            Err(RowsIterError::InvalidSchema { errors }) => {
+                assert!(matches!(
+                    &errors[..],
+                    [RowsIterError::MissingColumn { suggestion: None, .. }]
+                ))
+            }
+            _ => panic!("expected schema error"),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use polars::df;
+use polars::{df, prelude::PolarsResult};
 use polars_rows_iter::*;
 
 #[derive(Debug, FromDataFrameRow)]
@@ -15,6 +15,22 @@ struct DataRow1 {
     _x: i32,
 }
 
+mod derive_import_only {
+    use polars_rows_iter::FromDataFrameRow;
+
+    #[derive(Debug, FromDataFrameRow)]
+    #[rows(filter = "is_active")]
+    pub struct DataRow2<'a> {
+        #[row_index]
+        pub index: usize,
+        #[column("col_x")]
+        pub x: i32,
+        #[column("col_y", on_null = "skip")]
+        pub y: &'a str,
+        pub z: Option<f64>,
+    }
+}
+
 #[test]
 fn sandbox() {
     let df = df!(
@@ -33,6 +49,44 @@ fn sandbox() {
     }
 }
 
+#[test]
+fn derive_should_decode_borrowed_fields() {
+    let df = df!(
+        "col_x" => [1i32, 2, 3, 4],
+        "col_y" => ["a", "b", "c", "d"]
+    )
+    .unwrap();
+
+    let rows = df
+        .rows_iter::<DataRow0>()
+        .unwrap()
+        .map(|row| row.map(|row| (row._x, row._y)))
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(vec![(1, "a"), (2, "b"), (3, "c"), (4, "d")], rows);
+}
+
+#[test]
+fn derive_should_only_need_the_macro_import() {
+    let df = df!(
+        "col_x" => [1i32, 2, 3, 4],
+        "col_y" => [Some("a"), None, Some("c"), Some("d")],
+        "z" => [Some(1.0f64), None, None, Some(4.0)],
+        "is_active" => [true, true, false, true]
+    )
+    .unwrap();
+
+    let rows = df
+        .rows_iter::<derive_import_only::DataRow2>()
+        .unwrap()
+        .map(|row| row.map(|row| (row.index, row.x, row.y, row.z)))
+        .collect::<PolarsResult<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(vec![(0, 1, "a", Some(1.0)), (3, 4, "d", Some(4.0))], rows);
+}
+
 // struct RowRowsIterator<'a> {
 //     col_a_iter: Box<dyn Iterator<Item = PolarsResult<i32>> + 'a>,
 //     col_b_iter: Box<dyn Iterator<Item = PolarsResult<&'a str>> + 'a>,